@@ -0,0 +1,58 @@
+// Benchmarks of the parse step and both parts of every day, over the worked examples and the real inputs.
+
+use common::Solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+/// Benchmarks parsing the input and solving each part separately. Each part is benchmarked on an already parsed model.
+fn bench<S: Solution>(c: &mut Criterion, name: &str, solution: &S, input: &str) {
+    let model = solution.parse(input).expect("Benchmark input should parse");
+    let mut group = c.benchmark_group(name);
+    group.bench_function("parse", |b| b.iter(|| solution.parse(black_box(input))));
+    group.bench_function("part 1", |b| b.iter(|| solution.part1(black_box(&model))));
+    group.bench_function("part 2", |b| b.iter(|| solution.part2(black_box(&model))));
+    group.finish();
+}
+
+fn examples(c: &mut Criterion) {
+    bench(c, "day01/example", &day01::Day01::default(), include_str!("../../day01/day01-input-example.txt"));
+    bench(c, "day02/example", &day02::Day02, include_str!("../../day02/day02-input-example.txt"));
+    bench(c, "day03/example", &day03::Day03, include_str!("../../day03/day03-input-example.txt"));
+    bench(c, "day04/example", &day04::Day04::default(), include_str!("../../day04/day04-input-example.txt"));
+    bench(c, "day05/example", &day05::Day05, include_str!("../../day05/day05-input-example.txt"));
+    bench(c, "day06/example", &day06::Day06, include_str!("../../day06/day06-input-example.txt"));
+    bench(c, "day07/example", &day07::Day07, include_str!("../../day07/day07-input-example.txt"));
+    bench(c, "day08/example", &day08::Day08 { connections: 10 }, include_str!("../../day08/day08-input-example.txt"));
+
+    // Day 11 has a different example for each part.
+    let day11 = day11::Day11;
+    let part1 = day11.parse(include_str!("../../day11/day11-input-example-part1.txt")).unwrap();
+    let part2 = day11.parse(include_str!("../../day11/day11-input-example-part2.txt")).unwrap();
+    let mut group = c.benchmark_group("day11/example");
+    group.bench_function("part 1", |b| b.iter(|| day11.part1(black_box(&part1))));
+    group.bench_function("part 2", |b| b.iter(|| day11.part2(black_box(&part2))));
+    group.finish();
+}
+
+fn inputs(c: &mut Criterion) {
+    bench(c, "day01/input", &day01::Day01::default(), include_str!("../../day01/day01-input.txt"));
+    bench(c, "day02/input", &day02::Day02, include_str!("../../day02/day02-input.txt"));
+    bench(c, "day03/input", &day03::Day03, include_str!("../../day03/day03-input.txt"));
+    bench(c, "day04/input", &day04::Day04::default(), include_str!("../../day04/day04-input.txt"));
+    bench(c, "day05/input", &day05::Day05, include_str!("../../day05/day05-input.txt"));
+    bench(c, "day06/input", &day06::Day06, include_str!("../../day06/day06-input.txt"));
+    bench(c, "day07/input", &day07::Day07, include_str!("../../day07/day07-input.txt"));
+    bench(c, "day08/input", &day08::Day08::default(), include_str!("../../day08/day08-input.txt"));
+    bench(c, "day11/input", &day11::Day11, include_str!("../../day11/day11-input.txt"));
+}
+
+criterion_group!(example_benches, examples);
+
+// The real inputs of some days take long enough that the default sample size would make the run very slow.
+criterion_group! {
+    name = input_benches;
+    config = Criterion::default().sample_size(10);
+    targets = inputs
+}
+
+criterion_main!(example_benches, input_benches);
@@ -0,0 +1,125 @@
+use common::Part;
+use std::{collections::HashMap, fmt, fs, io, path::Path};
+
+/// Known answers, read from a TOML file with a table per day and a key per part:
+///
+/// ```toml
+/// [day01]
+/// part1 = 1150
+/// part2 = "6738"
+/// ```
+///
+/// Answers may be integers or strings.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u32, Part), String>,
+}
+
+/// Result of comparing a computed answer with the known answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => f.pad("pass"),
+            Status::Fail => f.pad("FAIL"),
+            Status::Unknown => f.pad("unknown"),
+        }
+    }
+}
+
+impl Answers {
+    /// Loads the answers from a file.
+    ///
+    /// # Returns
+    /// * `Ok(Answers)`, or a message describing why the file could not be read or parsed.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read the answers file \"{}\": {}", path.display(), e))?;
+        text.parse()
+            .map_err(|e| format!("Invalid answers file \"{}\": {}", path.display(), e))
+    }
+
+    /// Loads the answers from a file if it exists. A missing file means no answers are known.
+    pub fn load_if_exists(path: impl AsRef<Path>) -> Result<Self, String> {
+        match fs::metadata(path.as_ref()) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            _ => Answers::load(path),
+        }
+    }
+
+    /// Returns the known answer for a day and part, if any.
+    pub fn expected(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compares a computed answer with the known answer.
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Status {
+        match self.expected(day, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(_) => Status::Fail,
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: toml::Table = s.parse().map_err(|e: toml::de::Error| e.message().to_string())?;
+        let mut answers = HashMap::new();
+        for (key, value) in &table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u32>().ok())
+                .ok_or_else(|| format!("Invalid day \"{}\". Expected e.g. \"day01\".", key))?;
+            let parts = value
+                .as_table()
+                .ok_or_else(|| format!("\"{}\" must be a table of parts.", key))?;
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("Invalid part \"{}.{}\". Expected part1 or part2.", key, name)),
+                };
+                let answer = match answer {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s.clone(),
+                    _ => return Err(format!("The answer \"{}.{}\" must be an integer or a string.", key, name)),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers { answers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_check() {
+        let answers: Answers = "[day01]\npart1 = 3\npart2 = \"6\"\n\n[day11]\npart1 = 5\n".parse().unwrap();
+        assert_eq!(answers.check(1, Part::One, "3"), Status::Pass);
+        assert_eq!(answers.check(1, Part::Two, "6"), Status::Pass);
+        assert_eq!(answers.check(11, Part::One, "4"), Status::Fail);
+        assert_eq!(answers.check(11, Part::Two, "4"), Status::Unknown);
+        assert_eq!(answers.check(2, Part::One, "4"), Status::Unknown);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("[dayone]\npart1 = 3\n".parse::<Answers>().is_err());
+        assert!("[day01]\npart3 = 3\n".parse::<Answers>().is_err());
+        assert!("[day01]\npart1 = 3.5\n".parse::<Answers>().is_err());
+        assert!("[day01\n".parse::<Answers>().is_err());
+    }
+}
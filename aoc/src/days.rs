@@ -0,0 +1,88 @@
+use common::{Part, Solution};
+use std::{
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// The workspace root. Default files are found relative to it, so the runner works from any directory.
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// The answer to one part, or a message if it failed, and the time taken to compute it.
+pub struct Answer {
+    pub part: Part,
+    pub result: Result<String, String>,
+    pub time: Duration,
+}
+
+/// The results of running a day's solution once.
+pub struct Run {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Runs a day's solution on the puzzle input for the given parts. Fails if the input cannot be parsed.
+pub type Runner = fn(&str, &[Part]) -> Result<Run, String>;
+
+/// The solution to a day's puzzle.
+pub struct Day {
+    pub number: u32,
+    pub run: Runner,
+}
+
+impl Day {
+    /// Returns the default location of the day's puzzle input, in the day's directory of the workspace.
+    pub fn default_input(&self) -> PathBuf {
+        Path::new(WORKSPACE).join(format!("day{:02}/day{:02}-input.txt", self.number, self.number))
+    }
+}
+
+/// Returns every implemented day in order.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day { number: 1, run: |s, parts| run(&day01::Day01::default(), s, parts) },
+        Day { number: 2, run: |s, parts| run(&day02::Day02, s, parts) },
+        Day { number: 3, run: |s, parts| run(&day03::Day03, s, parts) },
+        Day { number: 4, run: |s, parts| run(&day04::Day04::default(), s, parts) },
+        Day { number: 5, run: |s, parts| run(&day05::Day05, s, parts) },
+        Day { number: 6, run: |s, parts| run(&day06::Day06, s, parts) },
+        Day { number: 7, run: |s, parts| run(&day07::Day07, s, parts) },
+        Day { number: 8, run: |s, parts| run(&day08::Day08::default(), s, parts) },
+        Day { number: 11, run: |s, parts| run(&day11::Day11, s, parts) },
+    ]
+}
+
+/// Returns the day with the given number, if it is implemented.
+pub fn find(number: u32) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
+
+/// Parses the input and solves each part, timing each step separately. A panic is converted into an error so that one
+/// failing part does not stop the others.
+fn run<S: Solution>(solution: &S, input: &str, parts: &[Part]) -> Result<Run, String> {
+    let start = Instant::now();
+    let model = catch(|| solution.parse(input).map_err(|e| e.to_string()))?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let result = catch(|| Ok(solution.answer(&model, part)));
+            Answer { part, result, time: start.elapsed() }
+        })
+        .collect();
+
+    Ok(Run { parse_time, answers })
+}
+
+/// Calls `f`, converting a panic into an error containing the panic message.
+fn catch<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        Err(payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| "panicked".to_string()))
+    })
+}
@@ -0,0 +1,255 @@
+// Advent of Code 2025 runner. Runs any or all of the days and prints the answers in a table.
+
+mod answers;
+mod days;
+mod timing;
+
+use answers::{Answers, Status};
+use common::{load::Input, Part};
+use days::Run;
+use std::{env, panic, path::Path, process::ExitCode, time::Duration};
+use timing::Stats;
+
+const USAGE: &str = "\
+Usage: aoc run <DAY|all> [--part 1|2|both] [--answers FILE] [--bench N] [INPUT]
+
+  DAY          Day number (e.g. 7), or \"all\" to run every day.
+  --part, -p   Run part 1, part 2, or both. By default, both parts are run.
+  --answers    TOML file of known answers to check against. Defaults to answers.toml in the
+               workspace root, which is only used with the default inputs and only if it exists.
+  --bench      Run each day N times and report the minimum, median, and maximum time of
+               each step.
+  INPUT        Puzzle input file, or \"-\" for stdin. Defaults to dayNN/dayNN-input.txt in the workspace.
+               May not be given with \"all\".
+
+The exit code is non-zero if any answer does not match the known answer or a day fails.";
+
+/// Default file of known answers, in the workspace root.
+const DEFAULT_ANSWERS: &str = "answers.toml";
+
+/// Options parsed from the command line.
+struct Options {
+    day: Option<u32>,
+    parts: Vec<Part>,
+    answers: Option<String>,
+    bench: Option<usize>,
+    input: Option<String>,
+}
+
+/// One row of the results table.
+struct Row {
+    day: u32,
+    part: Part,
+    answer: Result<String, String>,
+    status: Status,
+    parse_time: Option<Duration>,
+    time: Option<Duration>,
+}
+
+/// Timings of one step of a day's solution over all runs.
+struct Timings {
+    day: u32,
+    step: String,
+    stats: Stats,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let days = match options.day {
+        Some(number) => match days::find(number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} is not implemented.", number);
+                return ExitCode::from(2);
+            }
+        },
+        None => days::all(),
+    };
+
+    // Known answers are for the default inputs, so the default file is not used with another input.
+    let answers = match (&options.answers, &options.input) {
+        (Some(path), _) => Answers::load(path),
+        (None, None) => Answers::load_if_exists(Path::new(days::WORKSPACE).join(DEFAULT_ANSWERS)),
+        (None, Some(_)) => Ok(Answers::default()),
+    };
+    let answers = match answers {
+        Ok(answers) => answers,
+        Err(message) => {
+            eprintln!("{}", message);
+            return ExitCode::from(2);
+        }
+    };
+
+    // Panics are reported in the table, so the default message and backtrace are not needed.
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut rows = Vec::new();
+    let mut timings = Vec::new();
+    for day in &days {
+        let source = options.input.as_deref().map_or_else(|| Input::from(day.default_input()), Input::from_arg);
+        let runs = source
+            .read()
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                (0..options.bench.unwrap_or(1))
+                    .map(|_| (day.run)(&input, &options.parts))
+                    .collect::<Result<Vec<Run>, String>>()
+            });
+
+        match runs {
+            Ok(runs) => {
+                // The answers and times in the table are from the first run.
+                let first = &runs[0];
+                for (i, answer) in first.answers.iter().enumerate() {
+                    let status = match &answer.result {
+                        Ok(text) => answers.check(day.number, answer.part, text),
+                        Err(_) => Status::Unknown,
+                    };
+                    rows.push(Row {
+                        day: day.number,
+                        part: answer.part,
+                        answer: answer.result.clone(),
+                        status,
+                        parse_time: (i == 0).then_some(first.parse_time),
+                        time: Some(answer.time),
+                    });
+                }
+                if options.bench.is_some() {
+                    timings.extend(step_timings(day.number, &runs));
+                }
+            }
+            Err(message) => {
+                for &part in &options.parts {
+                    rows.push(Row {
+                        day: day.number,
+                        part,
+                        answer: Err(message.clone()),
+                        status: Status::Unknown,
+                        parse_time: None,
+                        time: None,
+                    });
+                }
+            }
+        }
+    }
+
+    print_table(&rows, &answers);
+    if let Some(n) = options.bench {
+        println!();
+        print_timings(&timings, n);
+    }
+
+    if rows.iter().any(|row| row.answer.is_err() || row.status == Status::Fail) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Collects the timings of the parse step and each part over all runs of a day.
+fn step_timings(day: u32, runs: &[Run]) -> Vec<Timings> {
+    let parse: Vec<Duration> = runs.iter().map(|run| run.parse_time).collect();
+    let mut timings = vec![Timings { day, step: "parse".to_string(), stats: Stats::new(&parse).unwrap() }];
+    for (i, answer) in runs[0].answers.iter().enumerate() {
+        let times: Vec<Duration> = runs.iter().map(|run| run.answers[i].time).collect();
+        timings.push(Timings { day, step: format!("part {}", answer.part), stats: Stats::new(&times).unwrap() });
+    }
+    timings
+}
+
+/// Parses the arguments following the program name.
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    match args.next().map(String::as_str) {
+        Some("run") => {}
+        Some(command) => return Err(format!("Unknown command \"{}\".", command)),
+        None => return Err("Missing command.".to_string()),
+    }
+
+    let day = match args.next().map(String::as_str) {
+        Some("all") => None,
+        Some(day) => Some(day.parse::<u32>().map_err(|_| format!("Invalid day \"{}\".", day))?),
+        None => return Err("Missing day.".to_string()),
+    };
+
+    let mut parts = Part::ALL.to_vec();
+    let mut answers = None;
+    let mut bench = None;
+    let mut input = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("Missing part after --part.")?;
+                parts = Part::select(Some(part), Part::Two)?;
+            }
+            "--answers" => {
+                let path = args.next().ok_or("Missing file after --answers.")?;
+                answers = Some(path.clone());
+            }
+            "--bench" => {
+                let n = args.next().ok_or("Missing number of runs after --bench.")?;
+                match n.parse::<usize>() {
+                    Ok(n) if n > 0 => bench = Some(n),
+                    _ => return Err(format!("Invalid number of runs \"{}\".", n)),
+                }
+            }
+            _ if input.is_none() => input = Some(arg.clone()),
+            _ => return Err(format!("Unexpected argument \"{}\".", arg)),
+        }
+    }
+
+    if day.is_none() && input.is_some() {
+        return Err("An input file cannot be given with \"all\".".to_string());
+    }
+
+    Ok(Options { day, parts, answers, bench, input })
+}
+
+/// Prints the results as a table with a header. Failed answers are followed by the expected answer.
+fn print_table(rows: &[Row], answers: &Answers) {
+    let texts: Vec<String> = rows
+        .iter()
+        .map(|row| match (&row.answer, row.status) {
+            (Ok(answer), Status::Fail) => {
+                format!("{} (expected {})", answer, answers.expected(row.day, row.part).unwrap_or_default())
+            }
+            (Ok(answer), _) => answer.clone(),
+            (Err(message), _) => format!("error: {}", message),
+        })
+        .collect();
+    let width = texts.iter().map(String::len).max().unwrap_or(0).max("Answer".len());
+
+    println!("Day  Part  Status   Parse     Solve     Answer");
+    println!("---  ----  -------  --------  --------  {}", "-".repeat(width));
+    for (row, text) in rows.iter().zip(texts) {
+        let status = if row.answer.is_err() { "error".to_string() } else { row.status.to_string() };
+        let parse_time = row.parse_time.map(timing::format).unwrap_or_default();
+        let time = row.time.map(timing::format).unwrap_or_default();
+        println!("{:>3}  {:>4}  {:<7}  {:>8}  {:>8}  {}", row.day, row.part, status, parse_time, time, text);
+    }
+}
+
+/// Prints the minimum, median, and maximum time of each step.
+fn print_timings(timings: &[Timings], runs: usize) {
+    println!("Timings over {} runs", runs);
+    println!("Day  Step    Min       Median    Max");
+    println!("---  ------  --------  --------  --------");
+    for t in timings {
+        println!(
+            "{:>3}  {:<6}  {:>8}  {:>8}  {:>8}",
+            t.day,
+            t.step,
+            timing::format(t.stats.min),
+            timing::format(t.stats.median),
+            timing::format(t.stats.max)
+        );
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+/// Minimum, median, and maximum of a set of timings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of the timings, or returns `None` if there are none. The median of an even number of
+    /// timings is the mean of the middle two.
+    pub fn new(timings: &[Duration]) -> Option<Self> {
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => sorted[n / 2],
+            _ => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+        Some(Stats { min: sorted[0], median, max: sorted[n - 1] })
+    }
+}
+
+/// Formats a duration with a unit suited to its size, e.g. "12.3 µs" or "1.50 s".
+pub fn format(duration: Duration) -> String {
+    let nanos = duration.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        (nanos, "ns")
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    let precision = if value < 10.0 { 2 } else if value < 100.0 { 1 } else { 0 };
+    format!("{:.*} {}", precision, value, unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::new(&[]), None);
+        assert_eq!(Stats::new(&[ms(3), ms(1), ms(2)]), Some(Stats { min: ms(1), median: ms(2), max: ms(3) }));
+        assert_eq!(Stats::new(&[ms(4), ms(1), ms(2), ms(8)]), Some(Stats { min: ms(1), median: ms(3), max: ms(8) }));
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(format(Duration::from_nanos(512)), "512 ns");
+        assert_eq!(format(Duration::from_nanos(12_345)), "12.3 µs");
+        assert_eq!(format(Duration::from_micros(1_500)), "1.50 ms");
+        assert_eq!(format(Duration::from_millis(2_250)), "2.25 s");
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    error,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Offsets of the 4 orthogonal neighbors (von Neumann neighborhood).
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all 8 surrounding neighbors (Moore neighborhood).
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1), (0, -1), (1, -1),
+    (-1,  0),          (1,  0),
+    (-1,  1), (0,  1), (1,  1),
+];
+
+/// A rectangular 2D grid stored in row-major order. Cells are addressed by `(x, y)`, where `x` is the column and `y` is
+/// the row.
+///
+/// # Example
+/// ```
+/// use common::grid::Grid;
+///
+/// let grid = Grid::from_rows(vec![vec!['.', 'S'], vec!['^', '.']]).unwrap();
+/// assert_eq!(grid.width(), 2);
+/// assert_eq!(grid.find(&'S'), Some((1, 0)));
+/// assert_eq!(grid.get(2, 0), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Error returned when rows of differing lengths are converted into a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRowError {
+    /// Index of the first row whose length differs from the first row.
+    pub row: usize,
+    /// Length of the first row.
+    pub expected: usize,
+    /// Length of the offending row.
+    pub found: usize,
+}
+
+impl fmt::Display for RaggedRowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Row {} has {} cells, but expected {}", self.row, self.found, self.expected)
+    }
+}
+
+impl error::Error for RaggedRowError {}
+
+impl<T> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid { width, height, cells: vec![value; width * height] }
+    }
+
+    /// Creates a grid from a vector of rows.
+    ///
+    /// # Returns
+    /// * `Ok(Grid)`, or an error if the rows are not all the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedRowError> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some((row, r)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(RaggedRowError { row, expected: width, found: r.len() });
+        }
+        let cells = rows.into_iter().flatten().collect();
+        Ok(Grid { width, height, cells })
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns true if `(x, y)` is inside the grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns the cell at `(x, y)`, or `None` if it is outside the grid.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.width + x])
+    }
+
+    /// Returns the cell at `(x, y)` mutably, or `None` if it is outside the grid.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the location `(x + dx, y + dy)`, or `None` if it is outside the grid.
+    pub fn offset(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let nx = x.checked_add_signed(dx)?;
+        let ny = y.checked_add_signed(dy)?;
+        self.contains(nx, ny).then_some((nx, ny))
+    }
+
+    /// Returns the row `y` as a slice.
+    ///
+    /// # Panics
+    /// Panics if `y` is outside the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    /// Returns an iterator over the cells of column `x`, top to bottom.
+    ///
+    /// # Panics
+    /// Panics if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Returns an iterator over the columns, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    /// Returns an iterator over every location in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Returns an iterator over every location and its cell in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns an iterator over the in-bounds locations at the given offsets from `(x, y)`.
+    pub fn neighbors<'a>(&'a self, x: usize, y: usize, offsets: &'a [(isize, isize)]) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&d| self.offset((x, y), d))
+    }
+
+    /// Returns an iterator over the in-bounds orthogonal neighbors of `(x, y)`.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &ORTHOGONAL)
+    }
+
+    /// Returns an iterator over the in-bounds orthogonal and diagonal neighbors of `(x, y)`.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &SURROUNDING)
+    }
+
+    /// Returns the location of the first cell (in row-major order) equal to `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|c| c == value)
+            .map(|i| (i % self.width, i / self.width))
+    }
+
+    /// Returns a new grid with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+
+    /// Converts the grid back into a vector of rows.
+    pub fn into_rows(self) -> Vec<Vec<T>> {
+        let width = self.width.max(1);
+        let mut cells = self.cells.into_iter();
+        (0..self.height).map(|_| cells.by_ref().take(width).collect()).collect()
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Grid<T> {
+    type Error = RaggedRowError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Grid::from_rows(rows)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", x, y, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside the {}x{} grid", x, y, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::from_rows(vec![
+            "abc".chars().collect(),
+            "def".chars().collect(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = sample();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn test_from_rows_ragged() {
+        let result = Grid::from_rows(vec![vec![1, 2], vec![3]]);
+        assert_eq!(result, Err(RaggedRowError { row: 1, expected: 2, found: 1 }));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = sample();
+        let rows: Vec<String> = grid.rows().map(|r| r.iter().collect()).collect();
+        assert_eq!(rows, vec!["abc", "def"]);
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = sample();
+        let mut n4: Vec<_> = grid.neighbors4(0, 0).collect();
+        n4.sort();
+        assert_eq!(n4, vec![(0, 1), (1, 0)]);
+        let mut n8: Vec<_> = grid.neighbors8(1, 1).collect();
+        n8.sort();
+        assert_eq!(n8, vec![(0, 0), (0, 1), (1, 0), (2, 0), (2, 1)]);
+    }
+
+    #[test]
+    fn test_find_and_set() {
+        let mut grid = sample();
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        grid[(1, 1)] = 'z';
+        assert_eq!(grid.find(&'z'), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_into_rows() {
+        let rows = vec![vec![1, 2], vec![3, 4]];
+        let grid = Grid::try_from(rows.clone()).unwrap();
+        assert_eq!(grid.into_rows(), rows);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::parse::Inclusive;
+
+/// An integer type that can be stored in an [`IntervalSet`].
+pub trait Integer: Copy + Ord {
+    /// Returns the next value, or `None` if this is the largest value.
+    fn successor(self) -> Option<Self>;
+    /// Returns the previous value, or `None` if this is the smallest value.
+    fn predecessor(self) -> Option<Self>;
+    /// Returns the number of values in `start..=end`. `start` must not be greater than `end`.
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i32, i64, u32, u64, usize);
+
+/// A set of integers stored as sorted, disjoint ranges. Overlapping and adjacent ranges are merged as they are
+/// inserted, so "3-5" and "6-8" are stored as "3-8".
+///
+/// # Example
+/// ```
+/// use common::{intervals::IntervalSet, parse::Inclusive};
+///
+/// let mut set = IntervalSet::new();
+/// set.insert(Inclusive::new(3, 5));
+/// set.insert(Inclusive::new(10, 14));
+/// set.insert(Inclusive::new(6, 8));
+/// assert!(set.contains(&7));
+/// assert!(!set.contains(&9));
+/// assert_eq!(set.len(), 11);
+/// assert_eq!(set.spans().collect::<Vec<_>>(), vec![Inclusive::new(3, 8), Inclusive::new(10, 14)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalSet<T> {
+    spans: Vec<Inclusive<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet { spans: Vec::new() }
+    }
+}
+
+impl<T: Integer> IntervalSet<T> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range` to the set, merging it with any ranges it overlaps or touches.
+    pub fn insert(&mut self, range: Inclusive<T>) {
+        // Spans before `first` end more than one before the range starts, and spans from `last` on start more than one
+        // after it ends. Everything in between overlaps or touches the range.
+        let first = self.spans.partition_point(|s| s.end.successor().is_some_and(|next| next < range.start));
+        let last = self.spans.partition_point(|s| range.end.successor().is_none_or(|next| s.start <= next));
+        let merged = if first < last {
+            Inclusive { start: range.start.min(self.spans[first].start), end: range.end.max(self.spans[last - 1].end) }
+        } else {
+            range
+        };
+        self.spans.splice(first..last, [merged]);
+    }
+
+    /// Returns true if `value` is in the set. This takes O(log n) time for n spans.
+    pub fn contains(&self, value: &T) -> bool {
+        let i = self.spans.partition_point(|s| s.end < *value);
+        self.spans.get(i).is_some_and(|s| s.start <= *value)
+    }
+
+    /// Returns the number of values in the set.
+    pub fn len(&self) -> u128 {
+        self.spans.iter().map(|s| T::count(s.start, s.end)).sum()
+    }
+
+    /// Returns true if the set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Returns an iterator over the disjoint spans of the set in increasing order. No two spans overlap or touch.
+    pub fn spans(&self) -> impl Iterator<Item = Inclusive<T>> + '_ {
+        self.spans.iter().copied()
+    }
+
+    /// Returns the set of values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for &span in &other.spans {
+            result.insert(span);
+        }
+        result
+    }
+
+    /// Returns the set of values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.spans.len() && j < other.spans.len() {
+            let (a, b) = (self.spans[i], other.spans[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start <= end {
+                spans.push(Inclusive { start, end });
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { spans }
+    }
+
+    /// Returns the set of values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let mut j = 0;
+        for a in &self.spans {
+            // Skip the spans of `other` that end before this span. They end before every later span too.
+            while j < other.spans.len() && other.spans[j].end < a.start {
+                j += 1;
+            }
+
+            // Cut out each span of `other` that overlaps this one. `start` is the first value not yet cut out or
+            // kept, or `None` if the rest of the span has been cut out.
+            let mut start = Some(a.start);
+            for b in other.spans[j..].iter().take_while(|b| b.start <= a.end) {
+                let Some(s) = start else { break };
+                if b.start > s {
+                    spans.push(Inclusive { start: s, end: b.start.predecessor().unwrap() });
+                }
+                start = if b.end < a.end { b.end.successor() } else { None };
+            }
+            if let Some(s) = start {
+                spans.push(Inclusive { start: s, end: a.end });
+            }
+        }
+        IntervalSet { spans }
+    }
+}
+
+impl<T: Integer> FromIterator<Inclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Inclusive<T>>>(ranges: I) -> Self {
+        IntervalSet { spans: merge(ranges) }
+    }
+}
+
+/// Merges ranges that overlap or touch, and returns the resulting disjoint ranges in increasing order. Nested and
+/// duplicate ranges are absorbed by the ranges containing them.
+///
+/// # Example
+/// ```
+/// use common::{intervals::merge, parse::Inclusive};
+///
+/// let merged = merge([Inclusive::new(6, 8), Inclusive::new(3, 5), Inclusive::new(10, 12)]);
+/// assert_eq!(merged, vec![Inclusive::new(3, 8), Inclusive::new(10, 12)]);
+/// ```
+pub fn merge<T: Integer>(ranges: impl IntoIterator<Item = Inclusive<T>>) -> Vec<Inclusive<T>> {
+    let mut sorted: Vec<Inclusive<T>> = ranges.into_iter().collect();
+    sorted.sort_unstable_by_key(|r| r.start);
+
+    let mut merged: Vec<Inclusive<T>> = Vec::with_capacity(sorted.len());
+    for range in sorted {
+        match merged.last_mut() {
+            // The range overlaps or touches the last merged range, so extend it.
+            Some(last) if last.end.successor().is_none_or(|next| range.start <= next) => {
+                last.end = last.end.max(range.end);
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[(i64, i64)]) -> IntervalSet<i64> {
+        ranges.iter().map(|&(start, end)| Inclusive::new(start, end)).collect()
+    }
+
+    fn spans(set: &IntervalSet<i64>) -> Vec<(i64, i64)> {
+        set.spans().map(|s| (s.start, s.end)).collect()
+    }
+
+    fn merged(ranges: &[(i64, i64)]) -> Vec<(i64, i64)> {
+        merge(ranges.iter().map(|&(start, end)| Inclusive::new(start, end)))
+            .into_iter()
+            .map(|r| (r.start, r.end))
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        assert_eq!(merged(&[]), vec![]);
+    }
+
+    #[test]
+    fn test_merge_single() {
+        assert_eq!(merged(&[(3, 5)]), vec![(3, 5)]);
+    }
+
+    #[test]
+    fn test_merge_disjoint() {
+        assert_eq!(merged(&[(10, 12), (3, 5), (7, 8)]), vec![(3, 5), (7, 8), (10, 12)]);
+    }
+
+    #[test]
+    fn test_merge_overlapping() {
+        assert_eq!(merged(&[(10, 14), (3, 5), (16, 20), (12, 18)]), vec![(3, 5), (10, 20)]);
+    }
+
+    #[test]
+    fn test_merge_touching() {
+        assert_eq!(merged(&[(6, 8), (3, 5)]), vec![(3, 8)]);
+        assert_eq!(merged(&[(i64::MIN, -1), (0, 0), (1, i64::MAX)]), vec![(i64::MIN, i64::MAX)]);
+    }
+
+    #[test]
+    fn test_merge_nested() {
+        assert_eq!(merged(&[(1, 10), (2, 3), (4, 10), (1, 1)]), vec![(1, 10)]);
+    }
+
+    #[test]
+    fn test_merge_duplicates() {
+        assert_eq!(merged(&[(3, 5), (3, 5), (3, 5)]), vec![(3, 5)]);
+        assert_eq!(merged(&[(7, 7), (7, 7)]), vec![(7, 7)]);
+    }
+
+    #[test]
+    fn test_insert() {
+        assert_eq!(spans(&set(&[(10, 14), (3, 5), (16, 20), (12, 18)])), vec![(3, 5), (10, 20)]);
+        assert_eq!(spans(&set(&[(1, 2), (7, 8), (4, 5), (3, 3), (6, 6)])), vec![(1, 8)]);
+        assert_eq!(spans(&set(&[(i64::MIN, 0), (1, i64::MAX)])), vec![(i64::MIN, i64::MAX)]);
+    }
+
+    #[test]
+    fn test_contains_and_len() {
+        let s = set(&[(3, 5), (10, 14), (16, 20), (12, 18)]);
+        assert!(s.contains(&3) && s.contains(&5) && s.contains(&17));
+        assert!(!s.contains(&2) && !s.contains(&6) && !s.contains(&21));
+        assert_eq!(s.len(), 14);
+        assert_eq!(set(&[(i64::MIN, i64::MAX)]).len(), 1 << 64);
+        assert!(IntervalSet::<i64>::new().is_empty());
+    }
+
+    #[test]
+    fn test_union() {
+        let a = set(&[(1, 3), (10, 12)]);
+        let b = set(&[(4, 5), (11, 20), (30, 31)]);
+        assert_eq!(spans(&a.union(&b)), vec![(1, 5), (10, 20), (30, 31)]);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = set(&[(1, 5), (10, 20)]);
+        let b = set(&[(3, 12), (15, 15), (20, 25)]);
+        assert_eq!(spans(&a.intersection(&b)), vec![(3, 5), (10, 12), (15, 15), (20, 20)]);
+        assert!(a.intersection(&set(&[(6, 9)])).is_empty());
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(0, 2), (5, 6), (10, 22), (25, 25)]);
+        assert_eq!(spans(&a.difference(&b)), vec![(3, 4), (7, 9), (23, 24), (26, 30)]);
+        assert!(a.difference(&set(&[(0, 40)])).is_empty());
+        assert_eq!(a.difference(&IntervalSet::new()), a);
+    }
+}
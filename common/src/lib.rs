@@ -1,2 +1,10 @@
+pub mod grid;
+pub mod intervals;
 pub mod load;
+pub mod parse;
+pub mod part;
 pub mod print;
+pub mod solution;
+
+pub use part::Part;
+pub use solution::Solution;
@@ -1,33 +1,186 @@
+use crate::grid::Grid;
 use std::{
     env,
+    error,
+    fmt,
     fs::read_to_string,
-    io,
-    path::Path,
+    io::{self, Read},
+    path::{Path, PathBuf},
 };
 
-/// Loads the entire contents of the file specified as the first command-line argument into a string.
-/// 
+/// Errors that can occur while loading puzzle input.
+#[derive(Debug)]
+pub enum Error {
+    /// The input file path was not provided as the first command-line argument.
+    MissingArgument,
+    /// The file could not be read.
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// Standard input could not be read.
+    Stdin(io::Error),
+    /// A line of a map differs in length from the first line. The line number is 1-based.
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+        policy: MapPolicy,
+    },
+    /// The contents could not be parsed. Line and column numbers are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl Error {
+    /// Creates a parse error at the given 1-based line and column.
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Error::Parse { line, column, message: message.into() }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingArgument => write!(f, "Please provide the input file path as the first argument."),
+            Error::Io { path, source } => write!(f, "Could not read the file \"{}\": {}", path.display(), source),
+            Error::Stdin(source) => write!(f, "Could not read standard input: {}", source),
+            Error::RaggedRow { line, expected, found, policy } => write!(
+                f,
+                "Line {} has {} characters, but expected {} ({} map)",
+                line, found, expected, policy
+            ),
+            Error::Parse { line, column, message } => write!(f, "Parse error at line {}, column {}: {}", line, column, message),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } | Error::Stdin(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// A source of puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// Standard input.
+    Stdin,
+    /// A file on disk.
+    File(PathBuf),
+    /// Text that is already in memory.
+    Text(String),
+}
+
+impl Input {
+    /// Interprets a command-line argument. `-` means standard input, and anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            Input::Stdin
+        } else {
+            Input::File(PathBuf::from(arg))
+        }
+    }
+
+    /// Returns the source specified as the first command-line argument.
+    pub fn from_args() -> Result<Self, Error> {
+        path().map(|path| match path.to_str() {
+            Some("-") => Input::Stdin,
+            _ => Input::File(path),
+        })
+    }
+
+    /// Creates a source from text in memory.
+    pub fn text(text: &str) -> Self {
+        Input::Text(text.to_string())
+    }
+
+    /// Reads the entire source into a string.
+    pub fn read(&self) -> Result<String, Error> {
+        match self {
+            Input::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(Error::Stdin)?;
+                Ok(text)
+            }
+            Input::File(path) => read_to_string(path)
+                .map_err(|source| Error::Io { path: path.clone(), source }),
+            Input::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+/// A string is interpreted the same way as a command-line argument (see [`Input::from_arg`]).
+impl From<&str> for Input {
+    fn from(arg: &str) -> Self {
+        Input::from_arg(arg)
+    }
+}
+
+impl From<&Path> for Input {
+    fn from(path: &Path) -> Self {
+        Input::File(path.to_path_buf())
+    }
+}
+
+impl From<PathBuf> for Input {
+    fn from(path: PathBuf) -> Self {
+        Input::File(path)
+    }
+}
+
+impl From<&PathBuf> for Input {
+    fn from(path: &PathBuf) -> Self {
+        Input::File(path.clone())
+    }
+}
+
+impl From<&Input> for Input {
+    fn from(input: &Input) -> Self {
+        input.clone()
+    }
+}
+
+/// Loads the entire contents of the file specified as the first command-line argument (`-` for stdin) into a string.
+///
 /// # Panics
 /// Panics if the file cannot be read or the argument is missing.
-/// 
+///
 /// # Example
 /// ```no_run
 /// let contents = common::load::string();
 /// println!("{}", contents);
 /// ```
 pub fn string() -> String {
-    let path = get_path();
+    Input::from_args()
+        .and_then(read_string)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
 
-    // Load data
-    read_to_string(&path)
-        .unwrap_or_else(|_| panic!("Could not read the file \"{}\"", path))
+/// Reads an entire source into a string.
+///
+/// # Arguments
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+///
+/// # Returns
+/// * `Ok(String)` with the contents of the source, or an error if it cannot be read.
+pub fn read_string(input: impl Into<Input>) -> Result<String, Error> {
+    input.into().read()
 }
 
-/// Loads lines of data from the file specified as the first command-line argument into a vector of strings.
-/// 
+/// Loads lines of data from the file specified as the first command-line argument (`-` for stdin) into a vector of strings.
+///
 /// # Panics
 /// Panics if the file cannot be read or the argument is missing.
-/// 
+///
 /// # Example
 /// ```no_run
 /// let lines = common::load::lines();
@@ -36,32 +189,30 @@ pub fn string() -> String {
 /// }
 /// ```
 pub fn lines() -> Vec<String> {
-    let path = get_path();
-
-    // Load data
-    read_lines(&path)
-        .unwrap_or_else(|_| panic!("Could not read the file \"{}\"", path))
+    Input::from_args()
+        .and_then(read_lines)
+        .unwrap_or_else(|e| panic!("{}", e))
 }
 
-/// Reads a file line by line into a vector of strings.
-/// 
+/// Reads a source line by line into a vector of strings.
+///
 /// # Arguments
-/// * `filename` - Path to the file to read.
-/// 
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+///
 /// # Returns
-/// * `Ok(Vec<String>)` with each line as a string, or an error if the file cannot be read.
-fn read_lines(filename: impl AsRef<Path>) -> io::Result<Vec<String>> {
-    read_to_string(filename)
+/// * `Ok(Vec<String>)` with each line as a string, or an error if the source cannot be read.
+pub fn read_lines(input: impl Into<Input>) -> Result<Vec<String>, Error> {
+    read_string(input)
         .map(|input|
             input.lines().map(str::to_string).collect()
         )
 }
 
-/// Loads a file specified as the first command-line argument and splits its contents by commas into a vector of strings.
-/// 
+/// Loads a file specified as the first command-line argument (`-` for stdin) and splits its contents by commas into a vector of strings.
+///
 /// # Panics
 /// Panics if the file cannot be read or the argument is missing.
-/// 
+///
 /// # Example
 /// ```no_run
 /// let values = common::load::comma_separated_values();
@@ -70,33 +221,52 @@ fn read_lines(filename: impl AsRef<Path>) -> io::Result<Vec<String>> {
 /// }
 /// ```
 pub fn comma_separated_values() -> Vec<String> {
-    let path = get_path();
-
-    // Load data
-    read_comma_separated_values(&path)
-        .unwrap_or_else(|_| panic!("Could not read the file \"{}\"", path))
+    Input::from_args()
+        .and_then(read_comma_separated_values)
+        .unwrap_or_else(|e| panic!("{}", e))
 }
 
-/// Reads an entire file and splits it by ',' into a vector of strings.
-/// 
+/// Reads an entire source and splits it by ',' into a vector of strings.
+///
 /// # Arguments
-/// * `filename` - Path to the file to read.
-/// 
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+///
 /// # Returns
-/// * `Ok(Vec<String>)` with each value as a string, or an error if the file cannot be read.
-fn read_comma_separated_values(filename: impl AsRef<Path>) -> io::Result<Vec<String>> {
-    read_to_string(filename)
+/// * `Ok(Vec<String>)` with each value as a string, or an error if the source cannot be read.
+pub fn read_comma_separated_values(input: impl Into<Input>) -> Result<Vec<String>, Error> {
+    read_string(input)
         .map(|input|
             input.split(',').map(|s| s.trim().to_string()).collect()
         )
 }
 
-/// Loads a file specified as the first command-line argument into a 2D array of characters.
-/// Each line becomes a row, and each character becomes an element.
-/// 
+/// How lines of differing lengths are handled when loading a map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapPolicy {
+    /// Rows are returned as they are, with whatever lengths they have.
+    Ragged,
+    /// Every row must be the same length as the first row.
+    Strict,
+    /// Rows shorter than the longest row are padded at the end with the given character.
+    Padded(char),
+}
+
+impl fmt::Display for MapPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapPolicy::Ragged => write!(f, "ragged"),
+            MapPolicy::Strict => write!(f, "strict"),
+            MapPolicy::Padded(c) => write!(f, "padded with {:?}", c),
+        }
+    }
+}
+
+/// Loads a file specified as the first command-line argument (`-` for stdin) into a 2D array of characters.
+/// Each line becomes a row, and each character becomes an element. Rows may have differing lengths.
+///
 /// # Panics
 /// Panics if the file cannot be read or the argument is missing.
-/// 
+///
 /// # Example
 /// ```no_run
 /// let map = common::load::map();
@@ -105,37 +275,126 @@ fn read_comma_separated_values(filename: impl AsRef<Path>) -> io::Result<Vec<Str
 /// }
 /// ```
 pub fn map() -> Vec<Vec<char>> {
-    let path = get_path();
+    map_with(MapPolicy::Ragged)
+}
 
-    // Load data
-    read_map(&path)
-        .unwrap_or_else(|_| panic!("Could not read the file \"{}\"", path))
+/// Loads a file specified as the first command-line argument (`-` for stdin) into a 2D array of characters, handling
+/// lines of differing lengths according to `policy`.
+///
+/// # Panics
+/// Panics if the file cannot be read, the argument is missing, or the map does not satisfy the policy.
+///
+/// # Example
+/// ```no_run
+/// use common::load::MapPolicy;
+///
+/// let map = common::load::map_with(MapPolicy::Padded(' '));
+/// let width = map[0].len();
+/// assert!(map.iter().all(|row| row.len() == width));
+/// ```
+pub fn map_with(policy: MapPolicy) -> Vec<Vec<char>> {
+    Input::from_args()
+        .and_then(|input| read_map_with(input, policy))
+        .unwrap_or_else(|e| panic!("{}", e))
 }
 
-/// Reads an entire file into a 2D array of characters.
-/// Each line becomes a row, and each character becomes an element.
-/// 
+/// Reads an entire source into a 2D array of characters.
+/// Each line becomes a row, and each character becomes an element. Rows may have differing lengths.
+///
 /// # Arguments
-/// * `filename` - Path to the file to read.
-/// 
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+///
 /// # Returns
-/// * `Ok(Vec<Vec<char>>)` with each row as a vector of characters, or an error if the file cannot be read.
-fn read_map(filename: impl AsRef<Path>) -> io::Result<Vec<Vec<char>>> {
-    read_to_string(filename)
-        .map(|input|
-            input
-                .lines()
-                .map(|line| line.chars().collect())
-                .collect()
-        )
+/// * `Ok(Vec<Vec<char>>)` with each row as a vector of characters, or an error if the source cannot be read.
+pub fn read_map(input: impl Into<Input>) -> Result<Vec<Vec<char>>, Error> {
+    read_map_with(input, MapPolicy::Ragged)
+}
+
+/// Reads an entire source into a 2D array of characters, handling lines of differing lengths according to `policy`.
+///
+/// # Arguments
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+/// * `policy` - How to handle lines of differing lengths.
+///
+/// # Returns
+/// * `Ok(Vec<Vec<char>>)` with each row as a vector of characters, or an error if the source cannot be read or, for
+///   [`MapPolicy::Strict`], a line differs in length from the first line.
+pub fn read_map_with(input: impl Into<Input>, policy: MapPolicy) -> Result<Vec<Vec<char>>, Error> {
+    let mut map: Vec<Vec<char>> = read_string(input)?
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+
+    match policy {
+        MapPolicy::Ragged => {}
+        MapPolicy::Strict => {
+            let expected = map.first().map_or(0, Vec::len);
+            if let Some((i, row)) = map.iter().enumerate().find(|(_, row)| row.len() != expected) {
+                return Err(Error::RaggedRow { line: i + 1, expected, found: row.len(), policy });
+            }
+        }
+        MapPolicy::Padded(c) => {
+            let width = map.iter().map(Vec::len).max().unwrap_or(0);
+            for row in map.iter_mut() {
+                row.resize(width, c);
+            }
+        }
+    }
+    Ok(map)
+}
+
+/// Loads a file specified as the first command-line argument (`-` for stdin) into a grid of characters.
+/// Each line becomes a row, and each character becomes a cell.
+///
+/// # Panics
+/// Panics if the file cannot be read, the argument is missing, or the lines are not all the same length.
+///
+/// # Example
+/// ```no_run
+/// let grid = common::load::grid();
+/// println!("{}x{}", grid.width(), grid.height());
+/// ```
+pub fn grid() -> Grid<char> {
+    Input::from_args()
+        .and_then(read_grid)
+        .unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Reads an entire source into a grid of characters.
+/// Each line becomes a row, and each character becomes a cell.
+///
+/// # Arguments
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+///
+/// # Returns
+/// * `Ok(Grid<char>)`, or an error if the source cannot be read or the lines are not all the same length.
+pub fn read_grid(input: impl Into<Input>) -> Result<Grid<char>, Error> {
+    read_grid_with(input, MapPolicy::Strict)
 }
 
-/// Loads a file specified as the first command-line argument into a 2D array of numbers.
+/// Reads an entire source into a grid of characters, handling lines of differing lengths according to `policy`.
+///
+/// # Arguments
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+/// * `policy` - How to handle lines of differing lengths. A grid must be rectangular, so [`MapPolicy::Ragged`] is
+///   treated as [`MapPolicy::Strict`].
+///
+/// # Returns
+/// * `Ok(Grid<char>)`, or an error if the source cannot be read or the lines cannot be made into a rectangle.
+pub fn read_grid_with(input: impl Into<Input>, policy: MapPolicy) -> Result<Grid<char>, Error> {
+    let policy = if policy == MapPolicy::Ragged { MapPolicy::Strict } else { policy };
+    read_map_with(input, policy).and_then(|map|
+        Grid::from_rows(map)
+            .map_err(|e| Error::RaggedRow { line: e.row + 1, expected: e.expected, found: e.found, policy })
+    )
+}
+
+/// Loads a file specified as the first command-line argument (`-` for stdin) into a 2D array of numbers.
 /// Each digit in the file is parsed as an i32. Non-digit characters are ignored.
-/// 
+///
 /// # Panics
 /// Panics if the file cannot be read or the argument is missing.
-/// 
+///
 /// # Example
 /// ```no_run
 /// let numbers = common::load::numbers_map();
@@ -144,23 +403,21 @@ fn read_map(filename: impl AsRef<Path>) -> io::Result<Vec<Vec<char>>> {
 /// }
 /// ```
 pub fn numbers_map() -> Vec<Vec<i32>> {
-    let path = get_path();
-
-    // Load data
-    read_numbers_map(&path)
-        .unwrap_or_else(|_| panic!("Could not read the file \"{}\"", path))
+    Input::from_args()
+        .and_then(read_numbers_map)
+        .unwrap_or_else(|e| panic!("{}", e))
 }
 
-/// Reads an entire file into a 2D array of numbers (i32).
+/// Reads an entire source into a 2D array of numbers (i32).
 /// Each digit in the file is parsed as an i32. Non-digit characters are ignored.
-/// 
+///
 /// # Arguments
-/// * `filename` - Path to the file to read.
-/// 
+/// * `input` - Source to read. A path, `"-"` for stdin, or an [`Input`].
+///
 /// # Returns
-/// * `Ok(Vec<Vec<i32>>)` with each row as a vector of numbers, or an error if the file cannot be read.
-fn read_numbers_map(filename: impl AsRef<Path>) -> io::Result<Vec<Vec<i32>>> {
-    read_to_string(filename)
+/// * `Ok(Vec<Vec<i32>>)` with each row as a vector of numbers, or an error if the source cannot be read.
+pub fn read_numbers_map(input: impl Into<Input>) -> Result<Vec<Vec<i32>>, Error> {
+    read_string(input)
         .map(|input|
             input
                 .lines()
@@ -179,10 +436,14 @@ fn read_numbers_map(filename: impl AsRef<Path>) -> io::Result<Vec<Vec<i32>>> {
 }
 
 /// Gets the path from the command line arguments
-fn get_path() -> String {
+///
+/// # Returns
+/// * `Ok(PathBuf)` with the first argument, or `Error::MissingArgument` if there is none.
+pub fn path() -> Result<PathBuf, Error> {
     env::args()
         .nth(1)
-        .unwrap_or_else(|| panic!("Please provide the input file path as the first argument."))
+        .map(PathBuf::from)
+        .ok_or(Error::MissingArgument)
 }
 
 #[cfg(test)]
@@ -233,6 +494,74 @@ mod tests {
     fn test_read_lines_file_not_found() {
         let path = std::path::PathBuf::from("/nonexistent/file.txt");
         let result = read_lines(&path);
-        assert!(result.is_ok() == false);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_read_string_error_reports_path() {
+        let path = std::path::PathBuf::from("/nonexistent/file.txt");
+        match read_string(&path) {
+            Err(Error::Io { path: p, .. }) => assert_eq!(p, path),
+            other => panic!("Expected an I/O error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_read_text_input() {
+        let lines = read_lines(Input::text("a\r\nb\r\n")).unwrap();
+        assert_eq!(lines, vec!["a", "b"]);
+        let map = read_map(Input::text("ab\ncd")).unwrap();
+        assert_eq!(map, vec![vec!['a', 'b'], vec!['c', 'd']]);
+    }
+
+    #[test]
+    fn test_read_grid() {
+        let grid = read_grid(Input::text("ab\ncd")).unwrap();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![['a', 'b'], ['c', 'd']]);
+        assert!(matches!(read_grid(Input::text("ab\nc")), Err(Error::RaggedRow { line: 2, .. })));
+        let grid = read_grid_with(Input::text("ab\nc"), MapPolicy::Padded('.')).unwrap();
+        assert_eq!(grid.row(1), ['c', '.']);
+        match read_grid_with(Input::text("ab\nc"), MapPolicy::Ragged) {
+            Err(e @ Error::RaggedRow { line: 2, policy: MapPolicy::Strict, .. }) => {
+                assert_eq!(e.to_string(), "Line 2 has 1 characters, but expected 2 (strict map)");
+            }
+            other => panic!("Expected a ragged row error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_read_map_strict() {
+        let result = read_map_with(Input::text("abc\nab\nabc"), MapPolicy::Strict);
+        match result {
+            Err(e @ Error::RaggedRow { line: 2, expected: 3, found: 2, policy: MapPolicy::Strict }) => {
+                assert_eq!(e.to_string(), "Line 2 has 2 characters, but expected 3 (strict map)");
+            }
+            other => panic!("Expected a ragged row error, got {:?}", other),
+        }
+        assert!(read_map_with(Input::text("ab\ncd"), MapPolicy::Strict).is_ok());
+    }
+
+    #[test]
+    fn test_read_map_padded() {
+        let map = read_map_with(Input::text("a\nabc\n"), MapPolicy::Padded(' ')).unwrap();
+        assert_eq!(map, vec![vec!['a', ' ', ' '], vec!['a', 'b', 'c']]);
+    }
+
+    #[test]
+    fn test_read_map_ragged() {
+        let map = read_map(Input::text("a\nabc")).unwrap();
+        assert_eq!(map, vec![vec!['a'], vec!['a', 'b', 'c']]);
+    }
+
+    #[test]
+    fn test_input_from_arg() {
+        assert_eq!(Input::from_arg("-"), Input::Stdin);
+        assert_eq!(Input::from_arg("input.txt"), Input::File(PathBuf::from("input.txt")));
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        let error = Error::parse(3, 7, "unexpected character 'x'");
+        assert_eq!(error.to_string(), "Parse error at line 3, column 7: unexpected character 'x'");
     }
 }
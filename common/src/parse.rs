@@ -0,0 +1,188 @@
+use crate::load::Error;
+use std::{fmt::Display, str::FromStr};
+
+/// An inclusive range of values, `start..=end`, where `start <= end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Inclusive<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Ord> Inclusive<T> {
+    /// Creates the range `start..=end`.
+    ///
+    /// # Panics
+    /// Panics if `start` is greater than `end`.
+    pub fn new(start: T, end: T) -> Self {
+        assert!(start <= end, "The start of a range must not be greater than its end");
+        Inclusive { start, end }
+    }
+
+    /// Returns true if `value` is in the range.
+    pub fn contains(&self, value: &T) -> bool {
+        self.start <= *value && *value <= self.end
+    }
+}
+
+/// What to do with a range whose start is greater than its end, e.g. "5-3".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reversed {
+    /// Return an error.
+    Reject,
+    /// Swap the bounds, so "5-3" is the same as "3-5".
+    Swap,
+}
+
+/// Parser for ranges written as "start-end", e.g. "11-22". Whitespace around the bounds is ignored, and the start may
+/// be negative.
+///
+/// # Example
+/// ```
+/// use common::parse::{Inclusive, Range, Reversed};
+///
+/// let ranges = Range::new().parse_list::<i64>("11-22, 95-115,\n998-1012").unwrap();
+/// assert_eq!(ranges[1], Inclusive::new(95, 115));
+///
+/// let range = Range::new().reversed(Reversed::Swap).parse::<i64>("5-3").unwrap();
+/// assert_eq!(range, Inclusive::new(3, 5));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Range {
+    reversed: Reversed,
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Range {
+    /// Creates a parser that rejects reversed ranges.
+    pub fn new() -> Self {
+        Range { reversed: Reversed::Reject }
+    }
+
+    /// Sets what to do with reversed ranges.
+    pub fn reversed(mut self, reversed: Reversed) -> Self {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Parses a single range. Errors are reported as if the text were at the start of the first line.
+    pub fn parse<T>(&self, text: &str) -> Result<Inclusive<T>, Error>
+    where
+        T: FromStr + Ord,
+        T::Err: Display,
+    {
+        self.parse_at(text, 1, 1)
+    }
+
+    /// Parses a single range that starts at the given 1-based line and column of the input. The position is used to
+    /// report errors.
+    pub fn parse_at<T>(&self, text: &str, line: usize, column: usize) -> Result<Inclusive<T>, Error>
+    where
+        T: FromStr + Ord,
+        T::Err: Display,
+    {
+        let column_of = |offset: usize| column + text[..offset].chars().count();
+        let leading = text.len() - text.trim_start().len();
+        let range = text.trim();
+        let first = range.chars().next().ok_or_else(|| Error::parse(line, column, "Missing range"))?;
+
+        // The separator is the first '-' after the first character, so that the start may be negative.
+        let dash = range[first.len_utf8()..]
+            .find('-')
+            .map(|i| i + first.len_utf8())
+            .ok_or_else(|| Error::parse(line, column_of(leading), format!("Missing '-' in range \"{}\"", range)))?;
+
+        let bound = |offset: usize, len: usize, name: &str| -> Result<T, Error> {
+            let raw = &text[offset..offset + len];
+            let number = raw.trim();
+            let position = column_of(offset + raw.len() - raw.trim_start().len());
+            if number.is_empty() {
+                return Err(Error::parse(line, position, format!("Missing {} of range \"{}\"", name, range)));
+            }
+            number
+                .parse()
+                .map_err(|e| Error::parse(line, position, format!("Invalid {} of range \"{}\": {}", name, number, e)))
+        };
+        let start = bound(leading, dash, "start")?;
+        let end = bound(leading + dash + 1, range.len() - dash - 1, "end")?;
+
+        match self.reversed {
+            _ if start <= end => Ok(Inclusive { start, end }),
+            Reversed::Swap => Ok(Inclusive { start: end, end: start }),
+            Reversed::Reject => {
+                Err(Error::parse(line, column_of(leading), format!("The start of range \"{}\" is after its end", range)))
+            }
+        }
+    }
+
+    /// Parses a list of ranges separated by commas, newlines, or both. Blank lines and a comma at the end of a line are
+    /// allowed, but an empty range between two commas is not.
+    pub fn parse_list<T>(&self, text: &str) -> Result<Vec<Inclusive<T>>, Error>
+    where
+        T: FromStr + Ord,
+        T::Err: Display,
+    {
+        let mut ranges = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let items: Vec<&str> = line.split(',').collect();
+            let mut column = 1;
+            for (j, item) in items.iter().enumerate() {
+                let last = j == items.len() - 1;
+                if !(last && item.trim().is_empty()) {
+                    ranges.push(self.parse_at(item, i + 1, column)?);
+                }
+                column += item.chars().count() + 1;
+            }
+        }
+        Ok(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(error: Error) -> (usize, usize) {
+        match error {
+            Error::Parse { line, column, .. } => (line, column),
+            e => panic!("Expected a parse error, but got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Range::new().parse::<i64>("3-5").unwrap(), Inclusive::new(3, 5));
+        assert_eq!(Range::new().parse::<i64>("  3 - 5 ").unwrap(), Inclusive::new(3, 5));
+        assert_eq!(Range::new().parse::<i64>("-5--3").unwrap(), Inclusive::new(-5, -3));
+        assert_eq!(Range::new().parse::<i64>("7-7").unwrap(), Inclusive::new(7, 7));
+    }
+
+    #[test]
+    fn test_parse_reversed() {
+        assert_eq!(position(Range::new().parse::<i64>(" 5-3").unwrap_err()), (1, 2));
+        assert_eq!(Range::new().reversed(Reversed::Swap).parse::<i64>("5-3").unwrap(), Inclusive::new(3, 5));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(position(Range::new().parse::<i64>("").unwrap_err()), (1, 1));
+        assert_eq!(position(Range::new().parse::<i64>("  35").unwrap_err()), (1, 3));
+        assert_eq!(position(Range::new().parse::<i64>("3-").unwrap_err()), (1, 3));
+        assert_eq!(position(Range::new().parse::<i64>("3-x").unwrap_err()), (1, 3));
+        assert_eq!(position(Range::new().parse_at::<i64>("1-99999999999999999999", 4, 10).unwrap_err()), (4, 12));
+        assert_eq!(position(Range::new().parse::<u8>("1-256").unwrap_err()), (1, 3));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let ranges = Range::new().parse_list::<u32>("1-2,3-4,\n\n 5-6 ,7-8\n").unwrap();
+        let expected: Vec<Inclusive<u32>> = [(1, 2), (3, 4), (5, 6), (7, 8)].map(|(a, b)| Inclusive::new(a, b)).to_vec();
+        assert_eq!(ranges, expected);
+        assert_eq!(position(Range::new().parse_list::<u32>("1-2,3-4\n5-6,,7-8").unwrap_err()), (2, 5));
+        assert_eq!(position(Range::new().parse_list::<u32>("1-2,3-4\n5-6,x-8").unwrap_err()), (2, 5));
+    }
+}
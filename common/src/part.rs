@@ -0,0 +1,86 @@
+use std::{env, fmt, str::FromStr};
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Both parts, in order.
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Returns the parts selected by the second command-line argument: `1`, `2`, or `both`. If there is no second
+    /// argument, `default` is selected.
+    ///
+    /// # Panics
+    /// Panics if the argument is not `1`, `2`, or `both`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use common::Part;
+    ///
+    /// for part in Part::from_args(Part::Two) {
+    ///     println!("part {}", part);
+    /// }
+    /// ```
+    pub fn from_args(default: Part) -> Vec<Part> {
+        Part::select(env::args().nth(2).as_deref(), default).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Returns the parts selected by an argument: `1`, `2`, or `both`. If there is no argument, `default` is selected.
+    /// Returns an error if the argument is anything else.
+    pub fn select(arg: Option<&str>, default: Part) -> Result<Vec<Part>, String> {
+        match arg {
+            None => Ok(vec![default]),
+            Some("both") => Ok(Part::ALL.to_vec()),
+            Some(arg) => arg
+                .parse()
+                .map(|part| vec![part])
+                .map_err(|_| format!("Invalid part \"{}\". Expected 1, 2, or both.", arg)),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("Invalid part \"{}\". Expected 1 or 2.", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        for part in Part::ALL {
+            assert_eq!(part.to_string().parse::<Part>(), Ok(part));
+        }
+        assert!("3".parse::<Part>().is_err());
+    }
+
+    #[test]
+    fn test_select() {
+        assert_eq!(Part::select(None, Part::Two), Ok(vec![Part::Two]));
+        assert_eq!(Part::select(Some("1"), Part::Two), Ok(vec![Part::One]));
+        assert_eq!(Part::select(Some("both"), Part::Two), Ok(Part::ALL.to_vec()));
+        assert!(Part::select(Some("summary"), Part::Two).is_err());
+    }
+}
@@ -1,8 +1,277 @@
+use crate::grid::Grid;
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+/// Prints a map with a border around it. Rows shorter than the longest row are padded with spaces.
 pub fn map(map: &[Vec<char>]) {
-    let width = map[0].len();
+    let width = map.iter().map(Vec::len).max().unwrap_or(0);
     println!("+{}+", "-".repeat(width));
     for row in map {
-        println!("|{}|", row.iter().collect::<String>());
+        println!("|{:width$}|", row.iter().collect::<String>(), width = width);
     }
     println!("+{}+", "-".repeat(width));
 }
+
+/// ANSI colors used to highlight cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// Returns the ANSI SGR code for the color.
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+/// A grid to be rendered as one frame of an animation, with its own highlights and an optional caption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub highlights: Vec<((usize, usize), Color)>,
+    pub caption: Option<String>,
+}
+
+/// Renders a grid of characters with optional highlighted cells, row/column rulers, and a border.
+///
+/// # Example
+/// ```
+/// use common::{grid::Grid, print::{Color, Renderer}};
+///
+/// let grid = Grid::from_rows(vec![vec!['.', '@'], vec!['@', '.']]).unwrap();
+/// let mut out = Vec::new();
+/// Renderer::new()
+///     .color(false)
+///     .highlight([(1, 0)], Color::Red)
+///     .render(&grid, &mut out)
+///     .unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "+--+\n|.@|\n|@.|\n+--+\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Renderer {
+    highlights: HashMap<(usize, usize), Color>,
+    border: bool,
+    rulers: bool,
+    color: bool,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderer {
+    /// Creates a renderer that draws a border and uses color, without rulers or highlights.
+    pub fn new() -> Self {
+        Renderer { highlights: HashMap::new(), border: true, rulers: false, color: true }
+    }
+
+    /// Highlights the given cells with a color. Later highlights replace earlier ones for the same cell.
+    pub fn highlight(mut self, cells: impl IntoIterator<Item = (usize, usize)>, color: Color) -> Self {
+        self.highlights.extend(cells.into_iter().map(|p| (p, color)));
+        self
+    }
+
+    /// Enables or disables the border.
+    pub fn border(mut self, on: bool) -> Self {
+        self.border = on;
+        self
+    }
+
+    /// Enables or disables the row and column rulers.
+    pub fn rulers(mut self, on: bool) -> Self {
+        self.rulers = on;
+        self
+    }
+
+    /// Enables or disables ANSI color. Without color, highlighted cells are drawn like any other cell.
+    pub fn color(mut self, on: bool) -> Self {
+        self.color = on;
+        self
+    }
+
+    /// Renders a grid to a writer.
+    pub fn render<W: Write>(&self, grid: &Grid<char>, out: &mut W) -> io::Result<()> {
+        self.render_with(grid, &self.highlights, out)
+    }
+
+    /// Renders a frame to a writer. The frame's highlights are drawn on top of the renderer's highlights, and the
+    /// caption, if any, is written above the grid.
+    pub fn render_frame<W: Write>(&self, frame: &Frame, out: &mut W) -> io::Result<()> {
+        if let Some(caption) = &frame.caption {
+            writeln!(out, "{}", caption)?;
+        }
+        let mut highlights = self.highlights.clone();
+        highlights.extend(frame.highlights.iter().copied());
+        self.render_with(&frame.grid, &highlights, out)
+    }
+
+    /// Renders a sequence of frames to a writer.
+    ///
+    /// With a `delay`, the screen is cleared before each frame and the renderer pauses after each one, which animates
+    /// the frames in a terminal. Without a delay, the frames are separated by blank lines, which is suitable for a
+    /// file.
+    pub fn animate<W: Write>(
+        &self,
+        frames: impl IntoIterator<Item = Frame>,
+        out: &mut W,
+        delay: Option<Duration>,
+    ) -> io::Result<()> {
+        for (i, frame) in frames.into_iter().enumerate() {
+            match delay {
+                Some(_) => write!(out, "\x1b[H\x1b[2J")?,
+                None if i > 0 => writeln!(out)?,
+                None => {}
+            }
+            self.render_frame(&frame, out)?;
+            if let Some(delay) = delay {
+                out.flush()?;
+                thread::sleep(delay);
+            }
+        }
+        Ok(())
+    }
+
+    /// Renders a grid to stdout.
+    pub fn print(&self, grid: &Grid<char>) {
+        self.render(grid, &mut io::stdout().lock())
+            .unwrap_or_else(|e| panic!("Could not write to stdout: {}", e));
+    }
+
+    fn render_with<W: Write>(
+        &self,
+        grid: &Grid<char>,
+        highlights: &HashMap<(usize, usize), Color>,
+        out: &mut W,
+    ) -> io::Result<()> {
+        let width = grid.width();
+
+        // Row labels are right-aligned to the width of the largest row number.
+        let label_width = if self.rulers { digits(grid.height().saturating_sub(1)) } else { 0 };
+        let margin = if self.rulers { " ".repeat(label_width + 1) } else { String::new() };
+        let inset = if self.border { " " } else { "" };
+
+        // The column ruler has one line per digit, most significant first. Higher digits are only shown on multiples
+        // of their place value to keep the ruler readable.
+        if self.rulers {
+            let places = digits(width.saturating_sub(1));
+            for place in (0..places).rev() {
+                let scale = 10usize.pow(place as u32);
+                let line: String = (0..width)
+                    .map(|x| {
+                        if place == 0 || x % scale == 0 {
+                            char::from_digit(((x / scale) % 10) as u32, 10).unwrap()
+                        } else {
+                            ' '
+                        }
+                    })
+                    .collect();
+                writeln!(out, "{}{}{}", margin, inset, line.trim_end())?;
+            }
+        }
+
+        if self.border {
+            writeln!(out, "{}+{}+", margin, "-".repeat(width))?;
+        }
+        for (y, row) in grid.rows().enumerate() {
+            if self.rulers {
+                write!(out, "{:>w$} ", y, w = label_width)?;
+            }
+            if self.border {
+                write!(out, "|")?;
+            }
+            for (x, &c) in row.iter().enumerate() {
+                match highlights.get(&(x, y)) {
+                    Some(color) if self.color => write!(out, "\x1b[1;{}m{}\x1b[0m", color.code(), c)?,
+                    _ => write!(out, "{}", c)?,
+                }
+            }
+            if self.border {
+                write!(out, "|")?;
+            }
+            writeln!(out)?;
+        }
+        if self.border {
+            writeln!(out, "{}+{}+", margin, "-".repeat(width))?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the number of decimal digits needed to write `n`.
+fn digits(n: usize) -> usize {
+    n.checked_ilog10().map_or(1, |d| d as usize + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<char> {
+        Grid::from_rows(vec![
+            "..@".chars().collect(),
+            "@..".chars().collect(),
+        ])
+        .unwrap()
+    }
+
+    fn render(renderer: &Renderer, grid: &Grid<char>) -> String {
+        let mut out = Vec::new();
+        renderer.render(grid, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_render_plain() {
+        let output = render(&Renderer::new().border(false), &sample());
+        assert_eq!(output, "..@\n@..\n");
+    }
+
+    #[test]
+    fn test_render_highlight() {
+        let output = render(&Renderer::new().border(false).highlight([(2, 0)], Color::Red), &sample());
+        assert_eq!(output, "..\x1b[1;31m@\x1b[0m\n@..\n");
+    }
+
+    #[test]
+    fn test_render_rulers() {
+        let grid = Grid::new(12, 11, '.');
+        let output = render(&Renderer::new().rulers(true), &grid);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "    0         1");
+        assert_eq!(lines[1], "    012345678901");
+        assert_eq!(lines[2], "   +------------+");
+        assert_eq!(lines[3], " 0 |............|");
+        assert_eq!(lines[13], "10 |............|");
+    }
+
+    #[test]
+    fn test_animate_without_delay() {
+        let frames = (0..2).map(|i| Frame {
+            grid: sample(),
+            highlights: vec![((0, 1), Color::Green)],
+            caption: Some(format!("Frame {}", i)),
+        });
+        let mut out = Vec::new();
+        Renderer::new().color(false).border(false).animate(frames, &mut out, None).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "Frame 0\n..@\n@..\n\nFrame 1\n..@\n@..\n");
+    }
+}
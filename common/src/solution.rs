@@ -0,0 +1,68 @@
+use crate::{load, Part};
+use std::fmt::Display;
+
+/// A solution to a day's puzzle. The input is parsed once into a model, and each part computes its answer from the
+/// model.
+///
+/// # Example
+/// ```
+/// use common::{load, Part, Solution};
+///
+/// struct Sum;
+///
+/// impl Solution for Sum {
+///     type Model = Vec<i64>;
+///     type Answer1 = i64;
+///     type Answer2 = i64;
+///
+///     fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+///         input
+///             .lines()
+///             .enumerate()
+///             .map(|(i, line)| line.parse().map_err(|e| load::Error::parse(i + 1, 1, format!("{}", e))))
+///             .collect()
+///     }
+///
+///     fn part1(&self, numbers: &Self::Model) -> i64 {
+///         numbers.iter().sum()
+///     }
+///
+///     fn part2(&self, numbers: &Self::Model) -> i64 {
+///         numbers.iter().product()
+///     }
+/// }
+///
+/// let numbers = Sum.parse("2\n3\n4").unwrap();
+/// assert_eq!(Sum.part1(&numbers), 9);
+/// assert_eq!(Sum.solve("2\n3\n4", Part::Two).unwrap(), "24");
+/// ```
+pub trait Solution {
+    /// The puzzle input in a form that the parts can work with.
+    type Model;
+    /// The answer to part 1.
+    type Answer1: Display;
+    /// The answer to part 2.
+    type Answer2: Display;
+
+    /// Parses the puzzle input into the model.
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error>;
+
+    /// Computes the answer to part 1.
+    fn part1(&self, model: &Self::Model) -> Self::Answer1;
+
+    /// Computes the answer to part 2.
+    fn part2(&self, model: &Self::Model) -> Self::Answer2;
+
+    /// Computes the answer to the given part as text.
+    fn answer(&self, model: &Self::Model, part: Part) -> String {
+        match part {
+            Part::One => self.part1(model).to_string(),
+            Part::Two => self.part2(model).to_string(),
+        }
+    }
+
+    /// Parses the puzzle input and computes the answer to the given part as text.
+    fn solve(&self, input: &str, part: Part) -> Result<String, load::Error> {
+        self.parse(input).map(|model| self.answer(&model, part))
+    }
+}
@@ -0,0 +1,303 @@
+// Advent of Code 2025, Day 1
+
+use common::{load, Solution};
+use std::{iter::Enumerate, slice};
+
+/// Direction in which the dial is turned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+/// A rotation of the dial by a number of clicks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub turn: Turn,
+    pub distance: u32,
+}
+
+/// A dial with positions 0 to `size - 1`. Turning right increases the position and turning left decreases it, wrapping
+/// around in both directions.
+///
+/// # Example
+/// ```
+/// use day01::{Dial, Event, Rotation, Turn};
+///
+/// let dial = Dial::new(10, 8);
+/// let rotations = [Rotation { turn: Turn::Right, distance: 12 }];
+/// let events: Vec<Event> = dial.events(&rotations).collect();
+/// assert_eq!(events, vec![Event::Passed(0), Event::Landed(0)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    size: u32,
+    position: u32,
+}
+
+/// The times the dial points at 0 during a single rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Zeros {
+    /// Number of times the dial passes through 0 without stopping there.
+    pub passes: u64,
+    /// True if the dial stops at 0.
+    pub landed: bool,
+}
+
+impl Default for Dial {
+    /// Returns the dial in the puzzle, with 100 positions and starting at 50.
+    fn default() -> Self {
+        Dial::new(100, 50)
+    }
+}
+
+impl Dial {
+    /// Creates a dial with `size` positions, pointing at `start`.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0 or `start` is not less than `size`.
+    pub fn new(size: u32, start: u32) -> Self {
+        assert!(size > 0, "A dial must have at least one position");
+        assert!(start < size, "Starting position {} is not on a dial with {} positions", start, size);
+        Dial { size, position: start }
+    }
+
+    /// Returns the number of positions on the dial.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Returns the position the dial is pointing at.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// Turns the dial and returns the number of times it passed through 0 and whether it stopped there.
+    pub fn rotate(&mut self, rotation: Rotation) -> Zeros {
+        let size = u64::from(self.size);
+        let position = u64::from(self.position);
+        let distance = u64::from(rotation.distance);
+
+        // Number of clicks before the dial first points at 0.
+        let to_zero = match rotation.turn {
+            Turn::Right => (size - position) % size,
+            Turn::Left => position,
+        };
+        let to_zero = if to_zero == 0 { size } else { to_zero };
+
+        // Every click that leaves the dial at 0, including the last one.
+        let hits = if distance >= to_zero { (distance - to_zero) / size + 1 } else { 0 };
+
+        self.position = match rotation.turn {
+            Turn::Right => ((position + distance) % size) as u32,
+            Turn::Left => ((position + size - distance % size) % size) as u32,
+        };
+        let landed = self.position == 0;
+        let passes = if landed && distance > 0 { hits - 1 } else { hits };
+        Zeros { passes, landed }
+    }
+
+    /// Turns the dial one click at a time and returns the number of times it passed through 0 and whether it stopped
+    /// there. This is a slow reference for [`Dial::rotate`].
+    pub fn rotate_by_clicks(&mut self, rotation: Rotation) -> Zeros {
+        let mut hits = 0;
+        for _ in 0..rotation.distance {
+            self.position = match rotation.turn {
+                Turn::Right => (self.position + 1) % self.size,
+                Turn::Left => (self.position + self.size - 1) % self.size,
+            };
+            if self.position == 0 {
+                hits += 1;
+            }
+        }
+        let landed = self.position == 0;
+        let passes = if landed && rotation.distance > 0 { hits - 1 } else { hits };
+        Zeros { passes, landed }
+    }
+
+    /// Returns an iterator over every time the dial passes through or lands on 0 while making the rotations.
+    pub fn events(self, rotations: &[Rotation]) -> Events<'_> {
+        Events { dial: self, rotations: rotations.iter().enumerate(), index: 0, passes: 0, landed: false }
+    }
+}
+
+/// Something that happened to the dial while making a rotation. Each event holds the index of the rotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The dial passed through 0 without stopping.
+    Passed(usize),
+    /// The dial stopped at 0 at the end of the rotation.
+    Landed(usize),
+}
+
+/// Iterator over the events of a sequence of rotations, in order. See [`Dial::events`].
+#[derive(Debug, Clone)]
+pub struct Events<'a> {
+    dial: Dial,
+    rotations: Enumerate<slice::Iter<'a, Rotation>>,
+    index: usize,
+    passes: u64,
+    landed: bool,
+}
+
+impl Iterator for Events<'_> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Event> {
+        loop {
+            if self.passes > 0 {
+                self.passes -= 1;
+                return Some(Event::Passed(self.index));
+            }
+            if self.landed {
+                self.landed = false;
+                return Some(Event::Landed(self.index));
+            }
+            let (index, &rotation) = self.rotations.next()?;
+            let zeros = self.dial.rotate(rotation);
+            self.index = index;
+            self.passes = zeros.passes;
+            self.landed = zeros.landed;
+        }
+    }
+}
+
+/// Solution to day 1. The answer is the password. In part 1, it is the number of times the dial lands on 0. In part 2,
+/// the number of times the dial passes 0 is included.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Day01 {
+    /// The dial at the start of the rotations.
+    pub dial: Dial,
+}
+
+impl Solution for Day01 {
+    type Model = Vec<Rotation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse(input)
+    }
+
+    fn part1(&self, rotations: &Self::Model) -> u64 {
+        count_zeros(self.dial, rotations).landings
+    }
+
+    fn part2(&self, rotations: &Self::Model) -> u64 {
+        let zeros = count_zeros(self.dial, rotations);
+        zeros.landings + zeros.passes
+    }
+}
+
+/// The total number of times the dial points at 0 over a sequence of rotations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ZeroCount {
+    /// Number of times the dial stopped at 0.
+    pub landings: u64,
+    /// Number of times the dial passed through 0 without stopping.
+    pub passes: u64,
+}
+
+/// Makes the rotations and counts the times the dial lands on and passes through 0. Unlike counting the
+/// [`Dial::events`], this takes time proportional to the number of rotations, however far the dial turns.
+pub fn count_zeros(mut dial: Dial, rotations: &[Rotation]) -> ZeroCount {
+    rotations.iter().fold(ZeroCount::default(), |count, &rotation| {
+        let zeros = dial.rotate(rotation);
+        ZeroCount { landings: count.landings + u64::from(zeros.landed), passes: count.passes + zeros.passes }
+    })
+}
+
+/// Parses the rotations, one per line, e.g. "R2" or "L3".
+pub fn parse(input: &str) -> Result<Vec<Rotation>, load::Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let mut chars = line.chars();
+            let turn = match chars.next() {
+                Some('R') => Turn::Right,
+                Some('L') => Turn::Left,
+                _ => return Err(load::Error::parse(i + 1, 1, format!("Invalid turn direction in \"{}\"", line))),
+            };
+            let distance = chars
+                .as_str()
+                .parse::<u32>()
+                .map_err(|e| load::Error::parse(i + 1, 2, format!("Invalid distance in \"{}\": {}", line, e)))?;
+            Ok(Rotation { turn, distance })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../day01-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let model = Day01::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::default().part1(&model), 3);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let model = Day01::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::default().part2(&model), 6);
+    }
+
+    #[test]
+    fn test_count_zeros_long_rotation() {
+        let rotations = [Rotation { turn: Turn::Right, distance: u32::MAX }];
+        let count = count_zeros(Dial::new(1, 0), &rotations);
+        assert_eq!(count, ZeroCount { landings: 1, passes: u64::from(u32::MAX) - 1 });
+    }
+
+    #[test]
+    fn test_count_zeros_matches_events() {
+        let rotations = Day01::default().parse(EXAMPLE).unwrap();
+        let count = count_zeros(Dial::default(), &rotations);
+        let events: Vec<Event> = Dial::default().events(&rotations).collect();
+        let landings = events.iter().filter(|e| matches!(e, Event::Landed(_))).count() as u64;
+        assert_eq!(count, ZeroCount { landings, passes: events.len() as u64 - landings });
+    }
+
+    #[test]
+    fn test_rotate_full_turns() {
+        // A whole number of turns from 0 passes 0 one time fewer than it turns, then lands on it.
+        let mut dial = Dial::new(100, 0);
+        assert_eq!(dial.rotate(Rotation { turn: Turn::Left, distance: 300 }), Zeros { passes: 2, landed: true });
+        assert_eq!(dial.rotate(Rotation { turn: Turn::Right, distance: 0 }), Zeros { passes: 0, landed: true });
+        assert_eq!(dial.rotate(Rotation { turn: Turn::Right, distance: 99 }), Zeros { passes: 0, landed: false });
+        assert_eq!(dial.position(), 99);
+    }
+
+    fn rotation() -> impl Strategy<Value = Rotation> {
+        (prop_oneof![Just(Turn::Left), Just(Turn::Right)], 0..2_000u32)
+            .prop_map(|(turn, distance)| Rotation { turn, distance })
+    }
+
+    fn dial() -> impl Strategy<Value = Dial> {
+        (1..500u32).prop_flat_map(|size| (0..size).prop_map(move |start| Dial::new(size, start)))
+    }
+
+    proptest! {
+        #[test]
+        fn test_rotate_matches_clicks(dial in dial(), rotations in prop::collection::vec(rotation(), 0..50)) {
+            let mut fast = dial;
+            let mut slow = dial;
+            for rotation in rotations {
+                prop_assert_eq!(fast.rotate(rotation), slow.rotate_by_clicks(rotation), "{:?} from {:?}", rotation, slow);
+                prop_assert_eq!(fast.position(), slow.position());
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("R10\nX5\n").is_err());
+        assert!(parse("L\n").is_err());
+        assert!(parse("R-5\n").is_err());
+    }
+}
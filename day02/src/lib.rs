@@ -0,0 +1,213 @@
+// Advent of Code 2025, Day 2
+
+use common::{
+    load,
+    parse::{Inclusive, Range},
+    Part,
+    Solution,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Solution to day 2. The answer is the sum of the invalid IDs in the ranges. In part 1, an invalid ID is a sequence of
+/// digits repeated twice. In part 2, it is a sequence of digits repeated at least twice.
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Model = Vec<Inclusive<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse_ranges(input)
+    }
+
+    fn part1(&self, ranges: &Self::Model) -> i64 {
+        sum_invalid(ranges, Part::One)
+    }
+
+    fn part2(&self, ranges: &Self::Model) -> i64 {
+        sum_invalid(ranges, Part::Two)
+    }
+}
+
+/// Returns the sum of the invalid IDs in the ranges. In part 1, an invalid ID is a sequence of digits repeated twice.
+/// In part 2, it is a sequence of digits repeated at least twice.
+///
+/// Rather than checking every number, the invalid IDs of each length are generated directly from their repeated
+/// blocks, so the time taken does not depend on the widths of the ranges.
+///
+/// # Panics
+/// Panics if the sum does not fit in an `i64`.
+pub fn sum_invalid(ranges: &[Inclusive<i64>], part: Part) -> i64 {
+    // Integer addition is associative, so the sum does not depend on how the ranges are split between threads.
+    #[cfg(feature = "parallel")]
+    let sum: i128 = ranges.par_iter().map(|range| sum_invalid_in(range, part)).sum();
+    #[cfg(not(feature = "parallel"))]
+    let sum: i128 = ranges.iter().map(|range| sum_invalid_in(range, part)).sum();
+    i64::try_from(sum).expect("The sum of the invalid IDs does not fit in an i64")
+}
+
+/// Returns the sum of the invalid IDs in a single range.
+fn sum_invalid_in(range: &Inclusive<i64>, part: Part) -> i128 {
+    let (start, end) = (i128::from(range.start), i128::from(range.end));
+    (1..=MAX_DIGITS)
+        .map(|length| match part {
+            Part::One if length.is_multiple_of(2) => sum_repeated(start, end, length, length / 2),
+            Part::One => 0,
+            Part::Two => sum_periodic(start, end, length),
+        })
+        .sum()
+}
+
+/// Returns the sum of the invalid IDs in the ranges by checking every number in them. This is the original solution,
+/// kept as a reference for [`sum_invalid`].
+pub fn sum_invalid_naive(ranges: &[Inclusive<i64>], part: Part) -> i64 {
+    // Gonna solve this the naive way. Iterate through all numbers in each range and look for doubled digits.
+    let mut sum: i64 = 0;
+    for range in ranges {
+        for number in range.start..=range.end {
+            let num_str = number.to_string();
+            if part == Part::Two {
+                if (2..=num_str.len()).any(|n| duplicated(&num_str, n)) {
+                    sum += number;
+                }
+            } else if duplicated(&num_str, 2) {
+                sum += number;
+            }
+        }
+    }
+    sum
+}
+
+/// Number of digits in the largest `i64`.
+const MAX_DIGITS: u32 = 19;
+
+/// Returns the sum of the numbers in `start..=end` with `length` digits that consist of a block of `period` digits
+/// repeated. `period` must divide `length`.
+fn sum_repeated(start: i128, end: i128, length: u32, period: u32) -> i128 {
+    // Such a number is its block times a multiplier of the form 1, 101, 1001001, etc.
+    let multiplier = (10i128.pow(length) - 1) / (10i128.pow(period) - 1);
+    let low = start.max(10i128.pow(length - 1));
+    let high = end.min(10i128.pow(length) - 1);
+    if low > high {
+        return 0;
+    }
+
+    // Any block that puts the number in the range has exactly `period` digits, because the range has been limited to
+    // numbers with `length` digits.
+    let first = (low + multiplier - 1) / multiplier;
+    let last = high / multiplier;
+    if first > last {
+        return 0;
+    }
+    multiplier * (first + last) * (last - first + 1) / 2
+}
+
+/// Returns the sum of the numbers in `start..=end` with `length` digits that consist of a block repeated at least
+/// twice.
+fn sum_periodic(start: i128, end: i128, length: u32) -> i128 {
+    // A number repeating with a period of `length / p` for several primes p is counted once for each prime, so
+    // inclusion-exclusion over the sets of primes is used to count it once. A number in the set for primes p and q
+    // repeats with a period of `length / (p * q)`.
+    let primes = prime_factors(length);
+    let mut sum = 0;
+    for mask in 1u32..(1 << primes.len()) {
+        let product: u32 = primes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| mask & (1 << i) != 0)
+            .map(|(_, p)| p)
+            .product();
+        let term = sum_repeated(start, end, length, length / product);
+        if mask.count_ones() % 2 == 1 {
+            sum += term;
+        } else {
+            sum -= term;
+        }
+    }
+    sum
+}
+
+/// Returns the distinct prime factors of `n` in increasing order.
+fn prime_factors(mut n: u32) -> Vec<u32> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            factors.push(p);
+            while n.is_multiple_of(p) {
+                n /= p;
+            }
+        }
+        p += 1;
+    }
+    if n > 1 {
+        factors.push(n);
+    }
+    factors
+}
+
+/// Parses a list of ranges separated by commas, e.g. "11-22,95-115".
+pub fn parse_ranges(input: &str) -> Result<Vec<Inclusive<i64>>, load::Error> {
+    Range::new().parse_list(input)
+}
+
+/// Returns true if all n parts are the same
+pub fn duplicated(s: &str, n: usize) -> bool {
+    let len = s.len();
+    // Must split evenly into n parts
+    if !len.is_multiple_of(n) {
+        return false;
+    }
+
+    let part_len = len / n;
+    let first_chunk = &s.as_bytes()[..part_len];
+    s.as_bytes()
+        .chunks(part_len)
+        .all(|chunk| chunk == first_chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../day02-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let model = Day02.parse(EXAMPLE).unwrap();
+        assert_eq!(Day02.part1(&model), 1227775554);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let model = Day02.parse(EXAMPLE).unwrap();
+        assert_eq!(Day02.part2(&model), 4174379265);
+    }
+
+    #[test]
+    fn test_prime_factors() {
+        assert_eq!(prime_factors(1), vec![]);
+        assert_eq!(prime_factors(12), vec![2, 3]);
+        assert_eq!(prime_factors(19), vec![19]);
+    }
+
+    #[test]
+    fn test_wide_range() {
+        // Every invalid ID with 2 digits, 11 to 99, and the only ones with 3 digits, 111 to 999.
+        assert_eq!(sum_invalid(&[Inclusive::new(1, 999)], Part::One), 495);
+        assert_eq!(sum_invalid(&[Inclusive::new(1, 999)], Part::Two), 495 + 4995);
+        assert_eq!(sum_invalid(&[Inclusive::new(i64::MAX - 10, i64::MAX)], Part::Two), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_naive(start in 0..10_000_000i64, width in 0..20_000i64) {
+            let ranges = [Inclusive::new(start, start + width)];
+            prop_assert_eq!(sum_invalid(&ranges, Part::One), sum_invalid_naive(&ranges, Part::One));
+            prop_assert_eq!(sum_invalid(&ranges, Part::Two), sum_invalid_naive(&ranges, Part::Two));
+        }
+    }
+}
@@ -0,0 +1,275 @@
+// Advent of Code 2025, Day 3
+
+use common::{load, Part, Solution};
+use std::{
+    error, fmt,
+    io::{self, Write},
+};
+
+/// Number of batteries used from each bank in part 1.
+pub const PART1_BATTERIES: usize = 2;
+/// Number of batteries used from each bank in part 2.
+pub const PART2_BATTERIES: usize = 12;
+
+/// Returns the number of batteries used from each bank in the given part.
+pub fn batteries(part: Part) -> usize {
+    match part {
+        Part::One => PART1_BATTERIES,
+        Part::Two => PART2_BATTERIES,
+    }
+}
+
+/// Solution to day 3. The answer is the total joltage. Part 1 uses 2 batteries from each bank, and part 2 uses 12.
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Model = Vec<Bank>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse(input)
+    }
+
+    /// # Panics
+    /// Panics if a bank has fewer than 2 batteries, which [`Day03::parse`] does not check.
+    fn part1(&self, banks: &Self::Model) -> u64 {
+        total_joltage(banks, Part::One)
+    }
+
+    /// # Panics
+    /// Panics if a bank has fewer than 12 batteries, which [`Day03::parse`] does not check.
+    fn part2(&self, banks: &Self::Model) -> u64 {
+        total_joltage(banks, Part::Two)
+    }
+}
+
+/// A bank of batteries. Each digit is the joltage of a battery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bank {
+    /// The 1-based line of the input that the bank is on.
+    pub line: usize,
+    pub digits: Vec<u32>,
+}
+
+impl Bank {
+    /// Parses a bank on the given 1-based line of the input. Every character must be a digit.
+    pub fn parse(text: &str, line: usize) -> Result<Bank, load::Error> {
+        if text.is_empty() {
+            return Err(load::Error::parse(line, 1, "Empty bank"));
+        }
+        let digits = text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .ok_or_else(|| load::Error::parse(line, i + 1, format!("Invalid battery '{}' in bank", c)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Bank { line, digits })
+    }
+
+    /// Returns an error if the bank has fewer batteries than the part needs.
+    pub fn check_length(&self, part: Part) -> Result<(), ShortBankError> {
+        if self.digits.len() < batteries(part) {
+            return Err(ShortBankError { line: self.line, found: self.digits.len(), part });
+        }
+        Ok(())
+    }
+
+    /// Chooses the batteries that give the largest joltage in the given part. Returns an error if the bank has fewer
+    /// batteries than the part needs.
+    pub fn select(&self, part: Part) -> Result<Selection, ShortBankError> {
+        self.check_length(part)?;
+        Ok(max_subsequence(&self.digits, batteries(part)).expect("The bank has enough batteries"))
+    }
+}
+
+/// A bank has fewer batteries than a part needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortBankError {
+    /// 1-based line number of the bank.
+    pub line: usize,
+    /// Number of batteries in the bank.
+    pub found: usize,
+    /// The part that needs more batteries.
+    pub part: Part,
+}
+
+impl fmt::Display for ShortBankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The bank on line {} has {} batteries, but part {} needs {}",
+            self.line,
+            self.found,
+            self.part,
+            batteries(self.part)
+        )
+    }
+}
+
+impl error::Error for ShortBankError {}
+
+/// Parses the banks of batteries, one per line. The number of batteries needed depends on the part, so the length of a
+/// bank is checked when it is used. See [`Bank::check_length`].
+pub fn parse(input: &str) -> Result<Vec<Bank>, load::Error> {
+    input.lines().enumerate().map(|(i, line)| Bank::parse(line, i + 1)).collect()
+}
+
+/// Returns the sum over all banks of the largest number formed by the part's number of batteries from each bank, in
+/// order.
+///
+/// # Panics
+/// Panics if a bank has fewer batteries than the part needs or the sum does not fit in a `u64`.
+pub fn total_joltage(banks: &[Bank], part: Part) -> u64 {
+    banks
+        .iter()
+        .map(|bank| {
+            bank.select(part)
+                .unwrap_or_else(|e| panic!("{}", e))
+                .value()
+                .expect("The joltage of a bank does not fit in a u64")
+        })
+        .try_fold(0u64, u64::checked_add)
+        .expect("The total joltage does not fit in a u64")
+}
+
+/// Writes the batteries chosen from each bank and the resulting joltage, for checking the answer by hand. Columns are
+/// 1-based.
+pub fn write_summary<W: Write>(banks: &[Bank], part: Part, out: &mut W) -> io::Result<()> {
+    for bank in banks {
+        match bank.select(part) {
+            Ok(selection) => {
+                let columns: Vec<String> = selection.indices.iter().map(|i| (i + 1).to_string()).collect();
+                writeln!(out, "Line {}: {} from columns {}", bank.line, selection, columns.join(", "))?;
+            }
+            Err(e) => writeln!(out, "{}", e)?,
+        }
+    }
+    Ok(())
+}
+
+/// Digits chosen from a bank, in their original order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selection {
+    /// Indices of the chosen digits in the bank, in increasing order.
+    pub indices: Vec<usize>,
+    /// The chosen digits.
+    pub digits: Vec<u32>,
+}
+
+impl Selection {
+    /// Returns the number formed by the digits, or `None` if it does not fit in a `u64`.
+    pub fn value(&self) -> Option<u64> {
+        self.digits
+            .iter()
+            .try_fold(0u64, |value, &d| value.checked_mul(10)?.checked_add(u64::from(d)))
+    }
+}
+
+impl fmt::Display for Selection {
+    /// Writes the digits, however many there are.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits: String = self.digits.iter().filter_map(|&d| char::from_digit(d, 10)).collect();
+        f.pad(&digits)
+    }
+}
+
+/// Returns the lexicographically largest subsequence of `k` digits, which is also the largest number that can be formed
+/// by `k` of the digits in order. Among equal subsequences, the one using the earliest digits is chosen. Returns `None`
+/// if there are fewer than `k` digits.
+///
+/// This takes O(n) time for n digits. A digit is dropped whenever a larger one follows it, as long as enough digits
+/// remain to make up `k`.
+///
+/// # Example
+/// ```
+/// use day03::max_subsequence;
+///
+/// let selection = max_subsequence(&[8, 1, 8, 1, 9, 1, 1], 3).unwrap();
+/// assert_eq!(selection.indices, vec![4, 5, 6]);
+/// assert_eq!(selection.value(), Some(911));
+/// ```
+pub fn max_subsequence(digits: &[u32], k: usize) -> Option<Selection> {
+    let mut drops = digits.len().checked_sub(k)?;
+    let mut stack: Vec<usize> = Vec::with_capacity(digits.len());
+    for (i, &d) in digits.iter().enumerate() {
+        while drops > 0 && stack.last().is_some_and(|&top| digits[top] < d) {
+            stack.pop();
+            drops -= 1;
+        }
+        stack.push(i);
+    }
+    stack.truncate(k);
+    let digits = stack.iter().map(|&i| digits[i]).collect();
+    Some(Selection { indices: stack, digits })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../day03-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let model = Day03.parse(EXAMPLE).unwrap();
+        assert_eq!(Day03.part1(&model), 357);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let model = Day03.parse(EXAMPLE).unwrap();
+        assert_eq!(Day03.part2(&model), 3121910778619);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse("12345\n12a45\n").unwrap_err();
+        assert!(matches!(error, load::Error::Parse { line: 2, column: 3, .. }), "{:?}", error);
+        let error = parse("12345\n\n12345\n").unwrap_err();
+        assert!(matches!(error, load::Error::Parse { line: 2, column: 1, .. }), "{:?}", error);
+        let banks = parse("12345\n123\n").unwrap();
+        let error = banks[1].select(Part::Two).unwrap_err();
+        assert_eq!(error, ShortBankError { line: 2, found: 3, part: Part::Two });
+        assert_eq!(error.to_string(), "The bank on line 2 has 3 batteries, but part 2 needs 12");
+    }
+
+    #[test]
+    fn test_short_banks_part1() {
+        // The banks are too short for part 2, but part 1 only needs 2 batteries from each.
+        let banks = Day03.parse("12345\n98765\n").unwrap();
+        assert_eq!(Day03.part1(&banks), 45 + 98);
+    }
+
+    #[test]
+    fn test_summary() {
+        let banks = parse("818181911112111\n12\n").unwrap();
+        let mut out = Vec::new();
+        write_summary(&banks, Part::One, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "Line 1: 92 from columns 7, 12\nLine 2: 12 from columns 1, 2\n");
+    }
+
+    #[test]
+    fn test_max_subsequence() {
+        let selection = max_subsequence(&[2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 3, 4, 2, 7, 8], 12).unwrap();
+        assert_eq!(selection.indices, vec![2, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+        assert_eq!(selection.value(), Some(434234234278));
+
+        // Equal digits are taken as early as possible.
+        assert_eq!(max_subsequence(&[9, 9, 1, 9], 2).unwrap().indices, vec![0, 1]);
+
+        assert_eq!(max_subsequence(&[1, 2, 3], 3).unwrap().indices, vec![0, 1, 2]);
+        assert_eq!(max_subsequence(&[1, 2, 3], 0).unwrap().value(), Some(0));
+        assert_eq!(max_subsequence(&[1, 2, 3], 4), None);
+    }
+
+    #[test]
+    fn test_max_subsequence_long() {
+        let digits: Vec<u32> = (0..40).map(|i| (i * 7 % 10) as u32).collect();
+        let selection = max_subsequence(&digits, 25).unwrap();
+        assert_eq!(selection.value(), None);
+        assert_eq!(selection.to_string(), "9896307418529630741852963");
+    }
+}
@@ -1,43 +1,26 @@
 // Advent of Code 2025, Day 3
 
-use common::load;
+use common::{load, Part, Solution};
+use day03::{write_summary, Day03};
+use std::{env, io};
 
 fn main() {
-    println!("Day 3, part {}", if cfg!(feature = "part2") { "2" } else { "1" });
+    let default_part = if cfg!(feature = "part2") { Part::Two } else { Part::One };
 
-    let banks = load::lines();
+    let input = load::string();
+    let banks = Day03.parse(&input).unwrap_or_else(|e| panic!("{}", e));
 
-    let mut joltage: u64 = 0;
+    // After the input file, the part and "summary", which lists the batteries chosen from each bank, may be given in
+    // either order.
+    let mut args: Vec<String> = env::args().skip(2).collect();
+    let summary = args.iter().position(|arg| arg == "summary").map(|i| args.remove(i)).is_some();
+    let parts = Part::select(args.first().map(String::as_str), default_part).unwrap_or_else(|e| panic!("{}", e));
 
-    for bank in banks {
-        let numbers: Vec<u32> = bank.chars().filter_map(|c| c.to_digit(10)).collect();
-
-        let count = if cfg!(feature = "part2") { 12 } else { 2 };
-        let mut next_i = 0;
-        let mut j: u64 = 0;
-        for c in 0..count {
-            let (i, v) = next_digit(&numbers[next_i..], count - c - 1);
-            next_i += i + 1;
-            j = j * 10 + v as u64;
+    for part in parts {
+        println!("Day 3, part {}", part);
+        if summary {
+            write_summary(&banks, part, &mut io::stdout().lock()).unwrap_or_else(|e| panic!("{}", e));
         }
-        joltage += j;
+        println!("Total joltage: {}", Day03.answer(&banks, part));
     }
-
-    println!("Total joltage: {}", joltage);
 }
-
-// Find the first number such that none of the following numbers are greater. Exclude the last n numbers.
-fn next_digit(numbers: &[u32], n: usize) -> (usize, u32) {
-    numbers
-        .iter()
-        .take(numbers.len().saturating_sub(n))
-        .enumerate()
-        .fold((0, numbers[0]), |(max_i, max_v), (i, &v)| {
-            if v > max_v {
-                (i, v)
-            } else {
-                (max_i, max_v)
-            }
-        })
-}
-
@@ -0,0 +1,387 @@
+// Advent of Code 2025, Day 4
+
+use common::{
+    grid::{Grid, ORTHOGONAL, SURROUNDING},
+    load,
+    print::{Color, Frame},
+    Solution,
+};
+
+/// Solution to day 4. In part 1, the answer is the number of cells that can be removed from the original map. In part
+/// 2, it is the total number of cells removed by repeatedly removing them. The rule decides which cells can be removed.
+#[derive(Debug, Clone, Default)]
+pub struct Day04 {
+    pub rule: Rule,
+}
+
+impl Solution for Day04 {
+    type Model = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse(input)
+    }
+
+    fn part1(&self, map: &Self::Model) -> usize {
+        part1(map, &self.rule)
+    }
+
+    fn part2(&self, map: &Self::Model) -> usize {
+        part2(map, &self.rule)
+    }
+}
+
+/// The cells that count as neighbors of a cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Neighborhood {
+    /// The 4 orthogonal neighbors.
+    VonNeumann,
+    /// The 8 orthogonal and diagonal neighbors.
+    Moore,
+    /// The cells at the given `(dx, dy)` offsets.
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    /// Returns the offsets of the neighbors.
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        match self {
+            Neighborhood::VonNeumann => &ORTHOGONAL,
+            Neighborhood::Moore => &SURROUNDING,
+            Neighborhood::Custom(offsets) => offsets,
+        }
+    }
+}
+
+/// Decides which cells can be removed. An occupied cell can be removed if fewer than `threshold` of its neighbors are
+/// occupied.
+///
+/// The default is the rule in the puzzle: cells marked `'@'` are occupied, the neighbors are the 8 surrounding cells,
+/// the map does not wrap around, and the threshold is 4.
+///
+/// # Example
+/// ```
+/// use day04::{Neighborhood, Rule};
+///
+/// let rule = Rule::new().symbol('#').neighborhood(Neighborhood::VonNeumann).wrap(true).threshold(2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    symbol: char,
+    neighborhood: Neighborhood,
+    wrap: bool,
+    threshold: usize,
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rule {
+    /// Creates the rule in the puzzle.
+    pub fn new() -> Self {
+        Rule { symbol: '@', neighborhood: Neighborhood::Moore, wrap: false, threshold: 4 }
+    }
+
+    /// Sets the symbol that marks an occupied cell.
+    pub fn symbol(mut self, symbol: char) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the cells that count as neighbors.
+    pub fn neighborhood(mut self, neighborhood: Neighborhood) -> Self {
+        self.neighborhood = neighborhood;
+        self
+    }
+
+    /// Sets whether neighbors past one edge of the map are taken from the opposite edge.
+    pub fn wrap(mut self, on: bool) -> Self {
+        self.wrap = on;
+        self
+    }
+
+    /// Sets the number of occupied neighbors at which a cell can no longer be removed.
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns true if the cell is occupied.
+    pub fn is_occupied(&self, cell: char) -> bool {
+        cell == self.symbol
+    }
+
+    /// Returns true if the cell at `p` is occupied and can be removed.
+    pub fn is_removable(&self, map: &Grid<char>, p: (usize, usize)) -> bool {
+        self.is_occupied(map[p]) && self.count_neighbors(map, p) < self.threshold
+    }
+
+    /// Returns the number of occupied neighbors of the cell at `p`. On a small map that wraps around, a cell may be the
+    /// neighbor of another more than once, and it is counted each time.
+    pub fn count_neighbors(&self, map: &Grid<char>, p: (usize, usize)) -> usize {
+        self.neighbors(map, p).filter(|&n| self.is_occupied(map[n])).count()
+    }
+
+    /// Returns an iterator over the neighbors of the cell at `p`.
+    pub fn neighbors<'a>(
+        &'a self,
+        map: &'a Grid<char>,
+        p: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.neighborhood.offsets().iter().filter_map(move |&d| self.offset(map, p, d))
+    }
+
+    /// Returns an iterator over the cells that have the cell at `p` as a neighbor. Unless the neighborhood is
+    /// symmetric, these are not the same as its neighbors.
+    pub fn dependents<'a>(
+        &'a self,
+        map: &'a Grid<char>,
+        p: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.neighborhood.offsets().iter().filter_map(move |&(dx, dy)| self.offset(map, p, (-dx, -dy)))
+    }
+
+    fn offset(&self, map: &Grid<char>, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        if self.wrap {
+            let x = (x as isize + dx).rem_euclid(map.width() as isize);
+            let y = (y as isize + dy).rem_euclid(map.height() as isize);
+            Some((x as usize, y as usize))
+        } else {
+            map.offset((x, y), (dx, dy))
+        }
+    }
+}
+
+/// Parses the map. `'@'` marks an occupied cell.
+pub fn parse(input: &str) -> Result<Grid<char>, load::Error> {
+    load::read_grid(load::Input::text(input))
+}
+
+/// Returns the number of cells that can be removed from the map.
+pub fn part1(map: &Grid<char>, rule: &Rule) -> usize {
+    map.positions().filter(|&p| rule.is_removable(map, p)).count()
+}
+
+/// Returns the total number of cells removed by repeatedly removing cells until none can be removed.
+pub fn part2(map: &Grid<char>, rule: &Rule) -> usize {
+    erode(map, rule).order.len()
+}
+
+/// Returns the total number of cells removed, by sweeping the whole grid repeatedly until a sweep removes nothing. This
+/// is the original solution, kept as a reference for [`erode`].
+pub fn part2_naive(map: &Grid<char>, rule: &Rule) -> usize {
+    let mut new_map = map.clone();
+    // Let's try the naive approach
+    let mut removed = 0;
+    loop {
+        let previous_removed = removed;
+        for p in map.positions() {
+            if rule.is_removable(&new_map, p) {
+                // Any character other than the symbol will do.
+                new_map[p] = if rule.symbol == '.' { ' ' } else { '.' };
+                removed += 1;
+            }
+        }
+        if removed == previous_removed {
+            break;
+        }
+    }
+    removed
+}
+
+/// The result of eroding a map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Erosion {
+    /// The removed cells, in the order they were removed. Cells in earlier waves come first.
+    pub order: Vec<(usize, usize)>,
+    /// The wave in which each cell was removed, starting at 1, or `None` if the cell was not removed.
+    pub waves: Grid<Option<usize>>,
+}
+
+impl Erosion {
+    /// Returns the number of waves.
+    pub fn wave_count(&self) -> usize {
+        self.order.last().and_then(|&p| self.waves[p]).unwrap_or(0)
+    }
+
+    /// Returns the number of cells removed in each wave, starting with the first.
+    pub fn wave_sizes(&self) -> Vec<usize> {
+        let mut sizes = vec![0; self.wave_count()];
+        for &p in &self.order {
+            if let Some(wave) = self.waves[p] {
+                sizes[wave - 1] += 1;
+            }
+        }
+        sizes
+    }
+
+    /// Returns a frame for each wave showing the map before the wave, followed by a frame showing the final map.
+    /// `map` must be the map that was eroded. Cells about to be removed are drawn as `'x'` and highlighted, and removed
+    /// cells are drawn as `'.'`.
+    pub fn frames(&self, map: &Grid<char>) -> Vec<Frame> {
+        let mut current = map.clone();
+        let mut frames = Vec::new();
+        let mut removed = self.order.as_slice();
+        for (i, size) in self.wave_sizes().into_iter().enumerate() {
+            let (wave, rest) = removed.split_at(size);
+            let mut grid = current.clone();
+            for &p in wave {
+                grid[p] = 'x';
+                current[p] = '.';
+            }
+            frames.push(Frame {
+                grid,
+                highlights: wave.iter().map(|&p| (p, Color::Red)).collect(),
+                caption: Some(format!("Wave {}: {} removed", i + 1, size)),
+            });
+            removed = rest;
+        }
+        frames.push(Frame {
+            grid: current,
+            highlights: Vec::new(),
+            caption: Some(format!("After {} waves: {} removed", self.wave_count(), self.order.len())),
+        });
+        frames
+    }
+}
+
+/// Repeatedly removes cells that the rule allows to be removed until none are left.
+///
+/// The cells are removed in waves. The first wave is every cell that can be removed from the original map, and each
+/// later wave is every cell that can be removed once the earlier waves are gone. Rather than sweeping the whole map
+/// for each wave, the number of occupied neighbors of every cell is kept up to date, and only the cells that have a
+/// removed cell as a neighbor are examined again. This takes time linear in the size of the map.
+pub fn erode(map: &Grid<char>, rule: &Rule) -> Erosion {
+    let mut counts = map.map(|_| 0);
+    for (p, &cell) in map.iter() {
+        if rule.is_occupied(cell) {
+            counts[p] = rule.count_neighbors(map, p);
+        }
+    }
+
+    let mut waves = map.map(|_| None);
+    let mut order = Vec::new();
+    let mut wave: Vec<(usize, usize)> = map
+        .iter()
+        .filter(|&(p, &cell)| rule.is_occupied(cell) && counts[p] < rule.threshold)
+        .map(|(p, _)| p)
+        .collect();
+    let mut number = 1;
+    for &p in &wave {
+        waves[p] = Some(number);
+    }
+
+    while !wave.is_empty() {
+        let mut next = Vec::new();
+        for &p in &wave {
+            for n in rule.dependents(map, p) {
+                // A cell is queued as soon as it can be removed, so it is never queued twice.
+                if rule.is_occupied(map[n]) && waves[n].is_none() {
+                    counts[n] -= 1;
+                    if counts[n] < rule.threshold {
+                        waves[n] = Some(number + 1);
+                        next.push(n);
+                    }
+                }
+            }
+        }
+        order.append(&mut wave);
+        wave = next;
+        number += 1;
+    }
+
+    Erosion { order, waves }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const EXAMPLE: &str = include_str!("../day04-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let model = Day04::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::default().part1(&model), 13);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let model = Day04::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::default().part2(&model), 43);
+    }
+
+    #[test]
+    fn test_erode_example() {
+        let map = parse(EXAMPLE).unwrap();
+        let erosion = erode(&map, &Rule::new());
+        assert_eq!(erosion.order.len(), 43);
+        assert_eq!(erosion.order.iter().filter(|&&p| erosion.waves[p] == Some(1)).count(), 13);
+        assert_eq!(erosion.wave_count(), 9);
+        assert_eq!(erosion.waves[(2, 0)], Some(1));
+        assert_eq!(erosion.waves[(0, 0)], None);
+    }
+
+    #[test]
+    fn test_waves_example() {
+        let map = parse(EXAMPLE).unwrap();
+        let erosion = erode(&map, &Rule::new());
+        assert_eq!(erosion.wave_sizes(), vec![13, 12, 7, 5, 2, 1, 1, 1, 1]);
+
+        let frames = erosion.frames(&map);
+        assert_eq!(frames.len(), 10);
+        assert_eq!(frames[0].caption.as_deref(), Some("Wave 1: 13 removed"));
+        assert_eq!(frames[0].grid.row(0).iter().collect::<String>(), "..xx.xx@x.");
+        assert_eq!(frames[0].highlights.len(), 13);
+        assert_eq!(frames[9].caption.as_deref(), Some("After 9 waves: 43 removed"));
+        let occupied = |grid: &Grid<char>| grid.iter().filter(|&(_, &c)| c == '@').count();
+        assert_eq!(occupied(&frames[9].grid), occupied(&map) - 43);
+    }
+
+    #[test]
+    fn test_rule_variants() {
+        let map = parse("###\n#.#\n###\n").unwrap();
+        let rule = Rule::new().symbol('#');
+        // Only the corners have fewer than 4 neighbors, and removing them leaves the edges with 2 each.
+        assert_eq!(part1(&map, &rule), 4);
+        assert_eq!(part2(&map, &rule), 8);
+        // With wrap-around, every cell has 7 neighbors.
+        assert_eq!(part2(&map, &rule.clone().wrap(true)), 0);
+        // The edges have 2 orthogonal neighbors, and the corners have 2 as well.
+        assert_eq!(part1(&map, &rule.clone().neighborhood(Neighborhood::VonNeumann).threshold(3)), 8);
+        // Only the cell to the right counts, so the right column goes first and the rest follow from right to left.
+        let right = rule.neighborhood(Neighborhood::Custom(vec![(1, 0)])).threshold(1);
+        assert_eq!(part1(&map, &right), 4);
+        assert_eq!(erode(&map, &right).wave_count(), 3);
+    }
+
+    fn map() -> impl Strategy<Value = Grid<char>> {
+        (1..20usize, 1..20usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(prop_oneof![Just('.'), Just('@'), Just('@')], width), height)
+                .prop_map(|rows| Grid::from_rows(rows).unwrap())
+        })
+    }
+
+    fn rule() -> impl Strategy<Value = Rule> {
+        let neighborhood = prop_oneof![
+            Just(Neighborhood::VonNeumann),
+            Just(Neighborhood::Moore),
+            prop::collection::vec((-2..=2isize, -2..=2isize), 0..6).prop_map(Neighborhood::Custom),
+        ];
+        (neighborhood, any::<bool>(), 0..6usize).prop_map(|(neighborhood, wrap, threshold)| {
+            Rule::new().neighborhood(neighborhood).wrap(wrap).threshold(threshold)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_erode_matches_naive(map in map(), rule in rule()) {
+            prop_assert_eq!(erode(&map, &rule).order.len(), part2_naive(&map, &rule));
+        }
+    }
+}
@@ -1,66 +1,89 @@
 // Advent of Code 2025, Day 4
 
-use common::load;
+use common::{
+    load,
+    print::{Frame, Renderer},
+    Part,
+    Solution,
+};
+use day04::{erode, Day04};
+use std::{
+    env,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+    time::Duration,
+};
 
-fn main() {
-    println!("Day 4, part {}", if cfg!(feature = "part2") { "2" } else { "1" });
-
-    let map = load::map();
-
-    if cfg!(feature = "part2") {
-        part2(&map);
-    } else {
-        part1(&map);
-    }
+/// Extra output for part 2.
+enum Report {
+    /// The number of cells removed in each wave.
+    Waves,
+    /// The map before each wave, animated or written to the given file or directory.
+    Frames(Option<String>),
 }
 
-fn part1(map: &[Vec<char>]) {
-    let count = map.iter().enumerate()
-        .map(|(y, row)| row.iter().enumerate()
-            .filter(|(x, cell)| **cell == '@' && count_neighbors(map, *x, y) < 4)
-            .count()
-        )
-        .sum::<usize>();
-    println!("Cells with less than 4 neighbors: {}", count);
-}
+fn main() {
+    let default_part = if cfg!(feature = "part2") { Part::Two } else { Part::One };
 
-fn count_neighbors(map: &[Vec<char>], x: usize, y: usize) -> usize {
-    neighboring_cells(map, x, y).filter(|&c| c == '@').count()
-}
+    let input = load::string();
+    let solution = Day04::default();
+    let map = solution.parse(&input).unwrap_or_else(|e| panic!("{}", e));
 
-fn neighboring_cells(map: &[Vec<char>], x: usize, y: usize) -> impl Iterator<Item = char> + '_ {
-    let height = map.len();
-    let width = map[0].len();
-    let x0 = x.saturating_sub(1);
-    let y0 = y.saturating_sub(1);
-    let x1 = (x + 1).min(width - 1);
-    let y1 = (y + 1).min(height - 1);
-    (y0..=y1).flat_map(move |ny| {
-        (x0..=x1)
-            .filter(move |&nx| nx != x || ny != y)
-            .map(move |nx| map[ny][nx])
-    })
-}
+    // After the input file, the part and a report may be given in either order. "waves" lists the number of cells
+    // removed in each wave of part 2, and "frames" shows the map before each wave. The frames are animated in the
+    // terminal, or written to a file or a directory if one follows "frames".
+    let mut args: Vec<String> = env::args().skip(2).collect();
+    let report = args.iter().position(|arg| arg == "waves" || arg == "frames").map(|i| {
+        if args.remove(i) == "waves" {
+            return Report::Waves;
+        }
+        let is_destination = i < args.len() && Part::select(Some(&args[i]), default_part).is_err();
+        Report::Frames(is_destination.then(|| args.remove(i)))
+    });
+    let parts = Part::select(args.first().map(String::as_str), default_part).unwrap_or_else(|e| panic!("{}", e));
 
-fn part2(map: &[Vec<char>]) {
-    let mut new_map = map.to_vec();
-    let height = new_map.len();
-    let width = new_map[0].len();
-    // Let's try the naive approach
-    let mut removed = 0;
-    loop {
-        let previous_removed = removed;
-        for y in 0..height {
-            for x in 0..width {
-                if new_map[y][x] == '@' && count_neighbors(&new_map, x, y) < 4 {
-                    new_map[y][x] = '.';
-                    removed += 1;
+    for part in parts {
+        println!("Day 4, part {}", part);
+        match part {
+            Part::One => println!("Cells that can be removed: {}", solution.part1(&map)),
+            Part::Two => {
+                let erosion = erode(&map, &solution.rule);
+                match &report {
+                    Some(Report::Waves) => {
+                        for (i, size) in erosion.wave_sizes().iter().enumerate() {
+                            println!("Wave {}: {} removed", i + 1, size);
+                        }
+                    }
+                    Some(Report::Frames(destination)) => {
+                        let frames = erosion.frames(&map);
+                        write_frames(frames, destination.as_deref()).unwrap_or_else(|e| panic!("{}", e));
+                    }
+                    None => {}
                 }
+                println!("Total cells removed: {}", erosion.order.len());
             }
         }
-        if removed == previous_removed {
-            break;
+    }
+}
+
+/// Animates the frames on stdout, or writes them without color to a file or to one file per frame in a directory.
+fn write_frames(frames: Vec<Frame>, destination: Option<&str>) -> io::Result<()> {
+    match destination {
+        None => Renderer::new().animate(frames, &mut io::stdout().lock(), Some(Duration::from_millis(250))),
+        Some(path) if Path::new(path).is_dir() => {
+            for (i, frame) in frames.iter().enumerate() {
+                let file = File::create(Path::new(path).join(format!("frame-{:02}.txt", i)))?;
+                let mut out = BufWriter::new(file);
+                Renderer::new().color(false).render_frame(frame, &mut out)?;
+                out.flush()?;
+            }
+            Ok(())
+        }
+        Some(path) => {
+            let mut out = BufWriter::new(File::create(path)?);
+            Renderer::new().color(false).animate(frames, &mut out, None)?;
+            out.flush()
         }
     }
-    println!("Total cells removed: {}", removed);
-}
\ No newline at end of file
+}
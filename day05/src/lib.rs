@@ -0,0 +1,102 @@
+// Advent of Code 2025, Day 5
+
+use common::{intervals::IntervalSet, load, parse::Range, Solution};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// The fresh ingredient IDs and the available ingredient IDs.
+pub struct Inventory {
+    pub fresh: IntervalSet<i64>,
+    pub ingredient_ids: Vec<i64>,
+}
+
+/// Solution to day 5. In part 1, the answer is the number of available ingredient IDs that are fresh. In part 2, it is
+/// the number of IDs covered by the fresh ranges.
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Model = Inventory;
+    type Answer1 = usize;
+    type Answer2 = u128;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse(input)
+    }
+
+    fn part1(&self, inventory: &Self::Model) -> usize {
+        part1(inventory)
+    }
+
+    fn part2(&self, inventory: &Self::Model) -> u128 {
+        part2(&inventory.fresh)
+    }
+}
+
+/// Parses the fresh ranges, one per line, followed by a blank line and the ingredient IDs, one per line.
+pub fn parse(input: &str) -> Result<Inventory, load::Error> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    // Split the lines into two sections: fresh ranges and ingredient IDs
+    let blank = lines
+        .iter()
+        .position(|line| line.is_empty())
+        .ok_or_else(|| load::Error::parse(lines.len() + 1, 1, "Missing blank line after the fresh ranges"))?;
+
+    // Parse the fresh ingredient ranges
+    let fresh = lines[..blank]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| Range::new().parse_at(line, i + 1, 1))
+        .collect::<Result<_, _>>()?;
+
+    // Parse the ingredient IDs
+    let ingredient_ids = lines[blank + 1..]
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|e| load::Error::parse(blank + i + 2, 1, format!("Invalid ingredient ID: {}", e)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Inventory { fresh, ingredient_ids })
+}
+
+/// Returns the number of available ingredient IDs that are fresh.
+pub fn part1(inventory: &Inventory) -> usize {
+    #[cfg(feature = "parallel")]
+    let ids = inventory.ingredient_ids.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let ids = inventory.ingredient_ids.iter();
+    ids.filter(|id| inventory.fresh.contains(id)).count()
+}
+
+/// Returns the number of IDs covered by the fresh ranges.
+pub fn part2(fresh: &IntervalSet<i64>) -> u128 {
+    fresh.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../day05-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let model = Day05.parse(EXAMPLE).unwrap();
+        assert_eq!(Day05.part1(&model), 3);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let model = Day05.parse(EXAMPLE).unwrap();
+        assert_eq!(Day05.part2(&model), 14);
+    }
+
+    #[test]
+    fn test_part2_touching_ranges() {
+        let model = Day05.parse("3-5\n6-8\n4-4\n\n1\n").unwrap();
+        assert_eq!(Day05.part2(&model), 6);
+    }
+}
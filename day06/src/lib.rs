@@ -0,0 +1,235 @@
+// Advent of Code 2025, Day 6
+
+use common::{
+    grid::Grid,
+    load::{self, MapPolicy},
+    Solution,
+};
+
+/// Solution to day 6. The answer is the sum of the results of the problems. Part 1 reads the numbers row by row, and
+/// part 2 reads them column by column.
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Model = Worksheet;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        Worksheet::parse(input)
+    }
+
+    fn part1(&self, worksheet: &Self::Model) -> i64 {
+        part1(worksheet)
+    }
+
+    fn part2(&self, worksheet: &Self::Model) -> i64 {
+        part2(worksheet)
+    }
+}
+
+/// The operation that combines the numbers of a problem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+impl Operation {
+    /// Returns the operation for a symbol, `'+'` or `'*'`.
+    pub fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '+' => Some(Operation::Add),
+            '*' => Some(Operation::Multiply),
+            _ => None,
+        }
+    }
+
+    /// Combines the numbers.
+    pub fn apply(self, numbers: &[i64]) -> i64 {
+        match self {
+            Operation::Add => numbers.iter().sum(),
+            Operation::Multiply => numbers.iter().product(),
+        }
+    }
+}
+
+/// A problem on the worksheet: a block of digits with an operation below it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// The 1-based column of the worksheet where the block starts.
+    pub column: usize,
+    pub operation: Operation,
+    /// The numbers read row by row, top to bottom. Blank rows are skipped.
+    pub rows: Vec<i64>,
+    /// The numbers read column by column, left to right, with the most significant digit at the top.
+    pub columns: Vec<i64>,
+}
+
+/// A worksheet of problems side by side. The problems are separated by columns of spaces, and the last line holds the
+/// operation of each problem.
+///
+/// # Example
+/// ```
+/// use day06::{Operation, Worksheet};
+///
+/// let worksheet = Worksheet::parse("12  3\n 4 56\n*  + \n").unwrap();
+/// assert_eq!(worksheet.problems[0].rows, vec![12, 4]);
+/// assert_eq!(worksheet.problems[0].columns, vec![1, 24]);
+/// assert_eq!(worksheet.problems[1].operation, Operation::Add);
+/// assert_eq!(worksheet.problems[1].columns, vec![5, 36]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Worksheet {
+    pub problems: Vec<Problem>,
+}
+
+impl Worksheet {
+    /// Parses a worksheet. Lines shorter than the longest line are treated as if they were padded with spaces, and
+    /// blank lines at the end are ignored.
+    pub fn parse(input: &str) -> Result<Worksheet, load::Error> {
+        let mut map = load::read_map_with(load::Input::text(input), MapPolicy::Padded(' '))?;
+        while map.last().is_some_and(|row| row.iter().all(|&c| c == ' ')) {
+            map.pop();
+        }
+        let grid = Grid::from_rows(map).expect("Padded rows are all the same length");
+        let Some(operations) = grid.height().checked_sub(1) else {
+            return Err(load::Error::parse(1, 1, "Missing the line of operations"));
+        };
+
+        // Every character must be a digit above the last line, or an operation on the last line.
+        for ((x, y), &c) in grid.iter() {
+            let valid = if y < operations { c.is_ascii_digit() } else { Operation::from_symbol(c).is_some() };
+            if c != ' ' && !valid {
+                return Err(load::Error::parse(y + 1, x + 1, format!("Unexpected '{}' in the worksheet", c)));
+            }
+        }
+
+        // Each run of columns that are not entirely blank is a problem.
+        let blank: Vec<bool> = grid.columns().map(|mut column| column.all(|&c| c == ' ')).collect();
+        let mut problems = Vec::new();
+        let mut x = 0;
+        while x < grid.width() {
+            if blank[x] {
+                x += 1;
+                continue;
+            }
+            let end = (x..grid.width()).find(|&e| blank[e]).unwrap_or(grid.width());
+            problems.push(Self::parse_problem(&grid, x, end, operations)?);
+            x = end;
+        }
+        Ok(Worksheet { problems })
+    }
+
+    /// Parses the problem in columns `start..end`. The operation is on line `operations`.
+    fn parse_problem(grid: &Grid<char>, start: usize, end: usize, operations: usize) -> Result<Problem, load::Error> {
+        let symbols: Vec<(usize, char)> =
+            (start..end).map(|x| (x, grid[(x, operations)])).filter(|&(_, c)| c != ' ').collect();
+        let operation = match symbols.as_slice() {
+            [(_, c)] => Operation::from_symbol(*c).unwrap(),
+            [] => return Err(load::Error::parse(operations + 1, start + 1, "Missing the operation of a problem")),
+            [_, (x, _), ..] => {
+                return Err(load::Error::parse(operations + 1, x + 1, "A problem has more than one operation"));
+            }
+        };
+
+        let number = |digits: &mut dyn Iterator<Item = (usize, usize)>| -> Result<Option<i64>, load::Error> {
+            let mut value: Option<i64> = None;
+            for (x, y) in digits {
+                if let Some(d) = grid[(x, y)].to_digit(10) {
+                    value = value
+                        .unwrap_or(0)
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(i64::from(d)))
+                        .map(Some)
+                        .ok_or_else(|| load::Error::parse(y + 1, x + 1, "The number is too large"))?;
+                }
+            }
+            Ok(value)
+        };
+
+        let mut rows = Vec::new();
+        for y in 0..operations {
+            let cells = &grid.row(y)[start..end];
+            // A space between the first and last digits of a row would make it two numbers.
+            let first = cells.iter().position(|&c| c != ' ');
+            let last = cells.iter().rposition(|&c| c != ' ');
+            if let (Some(first), Some(last)) = (first, last)
+                && let Some(gap) = cells[first..last].iter().position(|&c| c == ' ')
+            {
+                let column = start + first + gap + 1;
+                return Err(load::Error::parse(y + 1, column, "A row of a problem has more than one number"));
+            }
+            rows.extend(number(&mut (start..end).map(|x| (x, y)))?);
+        }
+
+        let mut columns = Vec::new();
+        for x in start..end {
+            columns.extend(number(&mut (0..operations).map(|y| (x, y)))?);
+        }
+
+        Ok(Problem { column: start + 1, operation, rows, columns })
+    }
+}
+
+/// Returns the sum of the results of the problems, reading the numbers row by row.
+pub fn part1(worksheet: &Worksheet) -> i64 {
+    worksheet.problems.iter().map(|p| p.operation.apply(&p.rows)).sum()
+}
+
+/// Returns the sum of the results of the problems, reading the numbers column by column.
+pub fn part2(worksheet: &Worksheet) -> i64 {
+    worksheet.problems.iter().map(|p| p.operation.apply(&p.columns)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../day06-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let model = Day06.parse(EXAMPLE).unwrap();
+        assert_eq!(Day06.part1(&model), 4277556);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let model = Day06.parse(EXAMPLE).unwrap();
+        assert_eq!(Day06.part2(&model), 3263827);
+    }
+
+    #[test]
+    fn test_worksheet_example() {
+        let worksheet = Worksheet::parse(EXAMPLE).unwrap();
+        assert_eq!(worksheet.problems.len(), 4);
+        let problem = &worksheet.problems[3];
+        assert_eq!(problem.column, 13);
+        assert_eq!(problem.operation, Operation::Add);
+        assert_eq!(problem.rows, vec![64, 23, 314]);
+        assert_eq!(problem.columns, vec![623, 431, 4]);
+    }
+
+    #[test]
+    fn test_worksheet_zero_column() {
+        // A column of zeros is a number, not a separator.
+        let worksheet = Worksheet::parse("10\n20\n+ \n").unwrap();
+        assert_eq!(worksheet.problems.len(), 1);
+        assert_eq!(worksheet.problems[0].columns, vec![12, 0]);
+    }
+
+    #[test]
+    fn test_worksheet_errors() {
+        let position = |input: &str| match Worksheet::parse(input).unwrap_err() {
+            load::Error::Parse { line, column, .. } => (line, column),
+            e => panic!("Expected a parse error, but got {:?}", e),
+        };
+        assert_eq!(position(""), (1, 1));
+        assert_eq!(position("12 3\n4x 5\n+  *\n"), (2, 2));
+        assert_eq!(position("12 3\n45 6\n-  *\n"), (3, 1));
+        assert_eq!(position("12 3\n45 6\n   *\n"), (3, 1));
+        assert_eq!(position("12 3\n45 6\n+* *\n"), (3, 2));
+        assert_eq!(position("1 2\n3 4\n + \n"), (1, 2));
+    }
+}
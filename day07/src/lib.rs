@@ -0,0 +1,101 @@
+// Advent of Code 2025, Day 7
+
+use common::{grid::Grid, load, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// Solution to day 7. In part 1, the answer is the number of splitters encountered by the beams. In part 2, it is the
+/// total number of timelines.
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Model = Grid<char>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse(input)
+    }
+
+    fn part1(&self, map: &Self::Model) -> i64 {
+        part1(map)
+    }
+
+    fn part2(&self, map: &Self::Model) -> i64 {
+        part2(map)
+    }
+}
+
+/// Parses the map. `'S'` marks the source of the beam and `'^'` marks a splitter.
+pub fn parse(input: &str) -> Result<Grid<char>, load::Error> {
+    load::read_grid(load::Input::text(input))
+}
+
+/// Returns the number of splitters encountered by the beams.
+pub fn part1(map: &Grid<char>) -> i64 {
+    // List of columns with beams
+    let mut beams = HashSet::new();
+
+    // Number of splitters encountered
+    let mut count: i64 = 0;
+
+    // Beams start at the source and move down the map
+    if let Some((x, y)) = map.find(&'S') {
+        beams.insert(x);
+        for y in y + 1..map.height() {
+            for x in beams.iter().cloned().collect::<Vec<_>>() {
+                if map[(x, y)] == '^' {
+                    count += 1;
+                    beams.remove(&x);
+                    for (nx, _) in map.neighbors(x, y, &[(-1, 0), (1, 0)]) {
+                        beams.insert(nx);
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Returns the total number of timelines.
+pub fn part2(map: &Grid<char>) -> i64 {
+    // List of timelines in the form of beams columns and their timeline counts
+    let mut timelines: HashMap<usize, i64> = HashMap::new();
+
+    // Beams start at the source and move down the map
+    if let Some((x, y)) = map.find(&'S') {
+        timelines.insert(x, 1);
+        for y in y + 1..map.height() {
+            // Scan each beam column for splitters in this row
+            for x in timelines.keys().cloned().collect::<Vec<_>>() {
+                if map[(x, y)] == '^' {
+                    let count = timelines.remove(&x).unwrap();
+                    for (nx, _) in map.neighbors(x, y, &[(-1, 0), (1, 0)]) {
+                        *timelines.entry(nx).or_insert(0) += count;
+                    }
+                }
+            }
+        }
+    }
+
+    // Count the total number of timelines
+    timelines.values().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../day07-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let model = Day07.parse(EXAMPLE).unwrap();
+        assert_eq!(Day07.part1(&model), 21);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let model = Day07.parse(EXAMPLE).unwrap();
+        assert_eq!(Day07.part2(&model), 40);
+    }
+}
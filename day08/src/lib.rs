@@ -0,0 +1,167 @@
+// Advent of Code 2025, Day 8
+
+use common::{load, Solution};
+
+/// The junction box locations and the distances between every pair of them, sorted from closest to farthest.
+pub struct Playground {
+    pub locations: Vec<(i64, i64, i64)>,
+    pub distances: Vec<((usize, usize), f64)>,
+}
+
+/// Solution to day 8. In part 1, the answer is the product of the sizes of the three largest circuits after connecting
+/// the closest junction boxes. In part 2, it is the product of the x coordinates of the last two junction boxes
+/// connected to form a single circuit.
+pub struct Day08 {
+    /// Number of junction boxes to connect in part 1.
+    pub connections: usize,
+}
+
+impl Default for Day08 {
+    fn default() -> Self {
+        Day08 { connections: 1000 }
+    }
+}
+
+impl Solution for Day08 {
+    type Model = Playground;
+    type Answer1 = usize;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse(input)
+    }
+
+    fn part1(&self, playground: &Self::Model) -> usize {
+        part1(playground, self.connections)
+    }
+
+    fn part2(&self, playground: &Self::Model) -> i64 {
+        part2(playground)
+    }
+}
+
+/// Parses the junction box locations, one "x,y,z" per line, and computes the distances between them.
+pub fn parse(input: &str) -> Result<Playground, load::Error> {
+    let locations = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let coordinates = line
+                .split(',')
+                .map(|s| s.trim().parse::<i64>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| load::Error::parse(i + 1, 1, format!("Invalid coordinate: {}", e)))?;
+            match coordinates[..] {
+                [x, y, z] => Ok((x, y, z)),
+                _ => Err(load::Error::parse(i + 1, 1, format!("Expected 3 coordinates, found {}", coordinates.len()))),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let distances = sorted_distances(&locations);
+    Ok(Playground { locations, distances })
+}
+
+/// Returns the product of the sizes of the three largest circuits after connecting the closest `connections` pairs of
+/// junction boxes.
+pub fn part1(playground: &Playground, connections: usize) -> usize {
+    let mut circuits = initial_circuits(&playground.locations);
+
+    // Connect the closest N junction boxes
+    for &(connection, _) in playground.distances.iter().take(connections) {
+        connect(&mut circuits, connection);
+    }
+
+    // Create a sorted list of circuit sizes
+    let mut circuit_sizes: Vec<usize> = Vec::new();
+    for c in circuits.iter() {
+        circuit_sizes.push(c.len());
+    }
+    circuit_sizes.sort();
+
+    // Return the product of the sizes of the three largest circuits
+    let m = circuit_sizes.len();
+    circuit_sizes[m - 1] * circuit_sizes[m - 2] * circuit_sizes[m - 3]
+}
+
+/// Returns the product of the x coordinates of the last two junction boxes connected to form a single circuit.
+pub fn part2(playground: &Playground) -> i64 {
+    let Playground { locations, distances } = playground;
+    let mut circuits = initial_circuits(locations);
+
+    // Connect junction boxes until all are connected
+    let mut index = 0;
+    while circuits.len() > 1 {
+        connect(&mut circuits, distances[index].0);
+        index += 1;
+    }
+    // Return the product of the x coordinates of the last connected connection
+    let connection = distances[index - 1].0;
+    let from = connection.0;
+    let to = connection.1;
+    locations[from].0 * locations[to].0
+}
+
+/// Computes the distances between all pairs of locations, sorted from closest to farthest.
+pub fn sorted_distances(locations: &[(i64, i64, i64)]) -> Vec<((usize, usize), f64)> {
+    let mut distances: Vec<((usize, usize), f64)> = Vec::new();
+    for (i, &(x1, y1, z1)) in locations.iter().enumerate() {
+        for (j, &(x2, y2, z2)) in locations.iter().enumerate().skip(i + 1) {
+            let dx = (x2 - x1) as f64;
+            let dy = (y2 - y1) as f64;
+            let dz = (z2 - z1) as f64;
+            let distance = (dx * dx + dy * dy + dz * dz).sqrt();
+            distances.push(((i, j), distance));
+        }
+    }
+
+    // Sort distances.
+    distances.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    distances
+}
+
+/// Creates the initial list of circuits, one per junction box.
+pub fn initial_circuits(locations: &[(i64, i64, i64)]) -> Vec<Vec<usize>> {
+    (0..locations.len()).map(|i| vec![i]).collect()
+}
+
+/// Connects two junction boxes, merging their circuits if they are different.
+pub fn connect(circuits: &mut Vec<Vec<usize>>, connection: (usize, usize)) {
+    let from = connection.0;
+    let to = connection.1;
+    let cf = containing_circuit(circuits, from).unwrap();
+    let ct = containing_circuit(circuits, to).unwrap();
+    // If junctions are in different circuits, then merge the circuits. Otherwise, do nothing.
+    if cf != ct {
+        // Merge circuits
+        let mut ct_clone = circuits[ct].clone();
+        circuits[cf].append(&mut ct_clone);
+        circuits.remove(ct);
+    }
+}
+
+/// Returns the index of the circuit containing the junction box, if any.
+pub fn containing_circuit(circuits: &[Vec<usize>], junction: usize) -> Option<usize> {
+    circuits.iter().position(|c| c.contains(&junction))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../day08-input-example.txt");
+
+    #[test]
+    fn test_part1_example() {
+        // The example connects the 10 closest pairs instead of 1000.
+        let solution = Day08 { connections: 10 };
+        let playground = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part1(&playground), 40);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let solution = Day08::default();
+        let playground = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part2(&playground), 25272);
+    }
+}
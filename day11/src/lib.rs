@@ -0,0 +1,180 @@
+// Advent of Code 2025, Day 11
+
+use common::{load, Solution};
+use std::collections::HashMap;
+
+/// Solution to day 11. In part 1, the answer is the number of paths from "you" to "out". In part 2, it is the number of
+/// paths from "svr" to "out" that pass through both "dac" and "fft".
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Model = HashMap<String, Vec<String>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Model, load::Error> {
+        parse(input)
+    }
+
+    fn part1(&self, dag: &Self::Model) -> usize {
+        part1(dag)
+    }
+
+    fn part2(&self, dag: &Self::Model) -> usize {
+        part2(dag)
+    }
+}
+
+/// Returns the number of paths from "you" to "out".
+pub fn part1(dag: &HashMap<String, Vec<String>>) -> usize {
+    // Recursively traverse the DAG from "you" to count all unique paths to "out" nodes.
+    count_paths(dag, "you", "out", "out")
+}
+
+/// Returns the number of paths from "svr" to "out" that pass through both "dac" and "fft".
+pub fn part2(dag: &HashMap<String, Vec<String>>) -> usize {
+    // Here is the plan.
+    // Count the number of paths from "fft" to "dac".
+    // If it is not zero, then
+    //     Count the number of paths from "dac" to "out".
+    //     Count the number of paths from "svr" to "fft".
+    //     Return the product of the three counts.
+    // Otherwise,
+    //     Count the number of paths from "svr" to "dac".
+    //     Count the number of paths from "dac" to "fft".
+    //     Count the number of paths from "fft" to "out". Return the product.
+
+
+    // Count the number of paths from "fft" to "dac".
+    let fft_to_dac = {
+        let mut dag = dag.clone();
+        let excluded = vec!["fft", "dac"];
+        reduce(&mut dag, &excluded);
+        count_paths(&dag, "fft", "dac", "out")
+    };
+
+    if fft_to_dac > 0 {
+        // Count the number of paths from "dac" to "out".
+        let dac_to_out = count_paths(dag, "dac", "out", "out");
+
+        // Count the number of paths from "svr" to "fft".
+        let svr_to_fft = {
+            let mut dag = dag.clone();
+            let excluded = vec!["svr", "fft"];
+            reduce(&mut dag, &excluded);
+            count_paths(&dag, "svr", "fft", "out")
+        };
+        svr_to_fft * fft_to_dac * dac_to_out
+    } else {
+        // Count the number of paths from "svr" to "dac".
+        let svr_to_dac = {
+            let mut dag = dag.clone();
+            let excluded = vec!["svr", "dac"];
+            reduce(&mut dag, &excluded);
+            count_paths(&dag, "svr", "dac", "out")
+        };
+
+        // Count the number of paths from "dac" to "fft".
+        let dac_to_fft = {
+            let mut dag = dag.clone();
+            let excluded = vec!["dac", "fft"];
+            reduce(&mut dag, &excluded);
+            count_paths(&dag, "dac", "fft", "out")
+        };
+
+        // Count the number of paths from "fft" to "out".
+        let fft_to_out = count_paths(dag, "fft", "out", "out");
+
+        svr_to_dac * dac_to_fft * fft_to_out
+    }
+}
+
+/// Parses the devices and their outputs, one "name: output output ..." per line.
+pub fn parse(input: &str) -> Result<HashMap<String, Vec<String>>, load::Error> {
+    // I am assuming the following:
+    //   1. The input describes a DAG (no cycles).
+    //   2. Each node is unique.
+    //   3. The node named "out" is terminal node and it only exists as an output of other nodes.
+
+    let mut dag: HashMap<String, Vec<String>> = HashMap::new();
+    for (i, line) in input.lines().enumerate() {
+        let (node_str, outputs_str) = line
+            .split_once(':')
+            .ok_or_else(|| load::Error::parse(i + 1, 1, format!("Missing ':' in \"{}\"", line)))?;
+        let node = node_str.trim().to_string();
+        let outputs: Vec<String> = outputs_str.split_whitespace().map(|s| s.to_string()).collect();
+        dag.insert(node, outputs);
+    }
+    Ok(dag)
+}
+
+/// Repeatedly replaces nodes whose only outputs are "out" with "out" itself, except for the excluded nodes. This does
+/// not change the number of paths to any excluded node, but it makes the DAG smaller.
+pub fn reduce(dag: &mut HashMap<String, Vec<String>>, excluded: &[&str]) {
+    loop {
+        let out = "out";
+        let mut to_remove = Vec::new();
+        for (node, outputs) in dag.iter() {
+            if !outputs.is_empty()
+                && !excluded.contains(&node.as_str())
+                && outputs.iter().all(|o| o == out)
+            {
+                to_remove.push(node.clone());
+            }
+        }
+
+        if to_remove.is_empty() {
+            break;
+        }
+
+        for node in &to_remove {
+            dag.get_mut(node).unwrap().clear();
+        }
+
+        for outputs in dag.values_mut() {
+            for output in outputs.iter_mut() {
+                if to_remove.contains(output) {
+                    *output = out.to_string();
+                }
+            }
+        }
+    }
+}
+
+
+/// Count all unique paths from one node to another in a DAG.
+/// Recursively traverses the DAG starting from `from` and counts all distinct paths that lead to `to`.
+pub fn count_paths(dag: &HashMap<String, Vec<String>>, from: &str, to: &str, terminal: &str) -> usize {
+    dag.get(from).map_or(0, |outputs| {
+        outputs.iter().fold(0, |acc, output| {
+            let rest = if output == to {
+                1
+            } else if output != terminal {
+                count_paths(dag, output, to, terminal)
+            } else {
+                0
+            };
+            acc + rest
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_PART1: &str = include_str!("../day11-input-example-part1.txt");
+    const EXAMPLE_PART2: &str = include_str!("../day11-input-example-part2.txt");
+
+    #[test]
+    fn test_part1_example() {
+        let dag = Day11.parse(EXAMPLE_PART1).unwrap();
+        assert_eq!(Day11.part1(&dag), 5);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let dag = Day11.parse(EXAMPLE_PART2).unwrap();
+        assert_eq!(Day11.part2(&dag), 2);
+    }
+}